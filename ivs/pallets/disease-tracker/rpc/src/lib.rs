@@ -0,0 +1,122 @@
+//! # Disease Tracker RPC
+//!
+//! JSON-RPC methods over the disease-tracker runtime API for off-chain clients.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::Bytes;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_disease_tracker_runtime_api::DiseaseTrackerApi as DiseaseTrackerRuntimeApi;
+use pallet_disease_tracker_runtime_api::UserState;
+
+/// A user's disease-tracker records as returned by `ivs_getUserState`
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RpcUserState {
+    /// Display name
+    pub name: Bytes,
+    /// Profile metadata
+    pub metadata: Bytes,
+    /// Registration block
+    pub registered_at: u64,
+    /// Is the profile active?
+    pub is_active: bool,
+    /// Number of contacts on record
+    pub contact_count: u32,
+    /// CID of the latest encrypted health status
+    pub health_cid: Option<Bytes>,
+    /// Disease the latest health status was uploaded for
+    pub health_disease_id: Option<Bytes>,
+    /// Block of the latest health upload
+    pub health_uploaded_at: Option<u64>,
+    /// CID of the latest encrypted IVS
+    pub ivs_cid: Option<Bytes>,
+}
+
+impl From<UserState> for RpcUserState {
+    fn from(state: UserState) -> Self {
+        let health = state.health_status;
+        Self {
+            name: state.profile.name.into_inner().into(),
+            metadata: state.profile.metadata.into_inner().into(),
+            registered_at: state.profile.registered_at,
+            is_active: state.profile.is_active,
+            contact_count: state.contact_count,
+            health_cid: health.as_ref().map(|status| status.cid.to_vec().into()),
+            health_disease_id: health
+                .as_ref()
+                .map(|status| status.disease_id.to_vec().into()),
+            health_uploaded_at: health.as_ref().map(|status| status.uploaded_at),
+            ivs_cid: state.ivs_cid.map(|cid| cid.into_inner().into()),
+        }
+    }
+}
+
+#[rpc(client, server)]
+pub trait DiseaseTrackerApi<BlockHash, AccountId> {
+    /// Profile, contact count, health status and IVS CID of `account` in one
+    /// snapshot, or `None` if the account is not registered
+    #[method(name = "ivs_getUserState")]
+    fn user_state(
+        &self,
+        account: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<Option<RpcUserState>>;
+}
+
+/// Serves the disease-tracker RPC methods from a client's runtime API
+pub struct DiseaseTracker<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> DiseaseTracker<C, B> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error code returned when the runtime call fails
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(desc: &'static str, error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, desc, Some(error.to_string()))
+}
+
+impl<C, Block, AccountId> DiseaseTrackerApiServer<<Block as BlockT>::Hash, AccountId>
+    for DiseaseTracker<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: DiseaseTrackerRuntimeApi<Block, AccountId>,
+    AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+    fn user_state(
+        &self,
+        account: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<Option<RpcUserState>> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        let state = self
+            .client
+            .runtime_api()
+            .user_state(at_hash, account)
+            .map_err(|e| runtime_error("Unable to query user state.", e))?;
+
+        Ok(state.map(Into::into))
+    }
+}
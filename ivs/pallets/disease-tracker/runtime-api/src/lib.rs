@@ -0,0 +1,49 @@
+//! # Disease Tracker Runtime API
+//!
+//! Read-only queries over the disease-tracker pallet for off-chain clients.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+pub use pallet_disease_tracker::{DiseaseMetadata, MissingDataPolicy, PublicProfile, UserState};
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for querying disease-tracker state
+    pub trait DiseaseTrackerApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Whether the chain holds any data about `who`
+        fn has_any_data(who: AccountId) -> bool;
+
+        /// Profile of `who` with only the fields they made public
+        fn public_profile(who: AccountId) -> Option<PublicProfile>;
+
+        /// Number of registered users
+        fn user_count() -> u32;
+
+        /// How all-users recomputes treat users without health data
+        fn recompute_missing_policy() -> MissingDataPolicy;
+
+        /// Users an all-users recompute should cover under the current policy
+        fn eligible_users() -> Vec<AccountId>;
+
+        /// Profile, contact count, health status and IVS CID of `who`,
+        /// or `None` if they are not registered
+        fn user_state(who: AccountId) -> Option<UserState>;
+
+        /// Display name, description and ICD-10 code of the tracked disease
+        fn disease_metadata() -> Option<DiseaseMetadata>;
+
+        /// Accounts within `depth` contact hops of `who`, nearest first
+        /// (depth and node count are capped by the pallet)
+        fn contacts_within(who: AccountId, depth: u8, max_nodes: u32) -> Vec<AccountId>;
+
+        /// Number of health and IVS records referencing `cid`; zero once
+        /// nothing references it and it can be unpinned
+        fn get_cid_refcount(cid: Vec<u8>) -> u32;
+    }
+}
@@ -17,11 +17,21 @@
 
 pub use pallet::*;
 
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
-    use sp_std::vec::Vec;
+    use sp_std::{collections::btree_set::BTreeSet, vec::Vec};
+
+    use crate::weights::WeightInfo;
 
     /// User profile information
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -36,6 +46,81 @@ pub mod pallet {
         pub is_active: bool,
     }
 
+    /// Which profile fields a user exposes publicly (all private by default)
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ProfileVisibility {
+        /// Display name is public
+        pub name_public: bool,
+        /// Metadata is public
+        pub metadata_public: bool,
+    }
+
+    /// Profile view with non-public fields withheld
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct PublicProfile {
+        /// Display name, if public
+        pub name: Option<BoundedVec<u8, ConstU32<64>>>,
+        /// Metadata, if public
+        pub metadata: Option<BoundedVec<u8, ConstU32<256>>>,
+        /// Registration timestamp
+        pub registered_at: u64,
+        /// Is user active?
+        pub is_active: bool,
+    }
+
+    /// Human-readable description of the tracked disease
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct DiseaseMetadata {
+        /// Full disease name for display
+        pub display_name: BoundedVec<u8, ConstU32<128>>,
+        /// Free-form description
+        pub description: BoundedVec<u8, ConstU32<512>>,
+        /// ICD-10 code (e.g., "U07.1")
+        pub icd_code: BoundedVec<u8, ConstU32<16>>,
+    }
+
+    /// Snapshot of a user's disease-tracker records at one block
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct UserState {
+        /// Registered profile
+        pub profile: UserProfile,
+        /// Number of contacts on record
+        pub contact_count: u32,
+        /// Latest encrypted health status, if uploaded
+        pub health_status: Option<EncryptedHealthStatus>,
+        /// CID of the latest encrypted IVS, if computed
+        pub ivs_cid: Option<BoundedVec<u8, ConstU32<128>>>,
+    }
+
+    /// How an all-users recompute treats users without health data
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum MissingDataPolicy {
+        /// Leave such users out of the recompute
+        #[default]
+        Skip,
+        /// Include them so the worker marks the request failed
+        Fail,
+    }
+
+    /// Who may call `register_user`
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum RegistrationMode {
+        /// Any signed account
+        #[default]
+        Open,
+        /// Only accounts in `AllowedRegistrars`
+        Allowlist,
+    }
+
+    /// Storage invariant reported by `verify_invariants`
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum InvariantCode {
+        /// `UserCount` differs from the number of profiles
+        UserCountMismatch,
+        /// `HealthReportedCount` differs from the number of health records
+        HealthReportedCountMismatch,
+    }
+
     /// Encrypted health status record
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct EncryptedHealthStatus {
@@ -49,8 +134,35 @@ pub mod pallet {
         pub encryption_version: u32,
         /// Joint public key identifier used for encryption
         pub public_key_id: BoundedVec<u8, ConstU32<64>>,
+        /// Ciphertext size in bytes, as declared by the uploader
+        pub size_bytes: u64,
+    }
+
+    /// A contact edge with when and for how long the contact happened
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub struct ContactRecord<AccountId> {
+        /// Contacted account
+        pub contact: AccountId,
+        /// Block at which the contact was recorded (0 = unknown, pre-migration)
+        pub contacted_at: u64,
+        /// Duration of the contact in blocks
+        pub duration_blocks: u32,
+        /// Contact intensity, read as a multiplier by the IVS computation
+        pub weight: u8,
     }
 
+    /// Weight of contacts added without one (and of pre-v3 contacts)
+    pub const DEFAULT_CONTACT_WEIGHT: u8 = 1;
+
+    /// Deepest traversal `get_contacts_within` performs, in hops
+    pub const MAX_TRAVERSAL_DEPTH: u8 = 3;
+
+    /// Most accounts `get_contacts_within` returns
+    pub const MAX_TRAVERSAL_NODES: u32 = 1_000;
+
+    /// Most records a paged user query scans per call, however few match
+    pub const MAX_QUERY_SCAN: u32 = 1_000;
+
     /// Encrypted IVS score record
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct EncryptedIVS {
@@ -62,16 +174,50 @@ pub mod pallet {
         pub parameters: BoundedVec<u8, ConstU32<128>>,
     }
 
+    /// `ComputeOrigin` for runtimes without a dedicated compute-network account
+    pub type DefaultComputeOrigin<T> =
+        frame_system::EnsureRoot<<T as frame_system::Config>::AccountId>;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
-        
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+
         /// Maximum number of contacts per user
         #[pallet::constant]
         type MaxContacts: Get<u32>;
+
+        /// Origin of the off-chain compute network, allowed to store IVS
+        /// results (see `DefaultComputeOrigin`)
+        type ComputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+        /// CID prefixes accepted by `validate_cid` (see `ivs_primitives::DefaultCidPrefixes`)
+        type AllowedCidPrefixes: Get<&'static [&'static [u8]]>;
+
+        /// Maximum health records inspected per block by the retention sweep,
+        /// also the number of contact lists scanned and exposure acknowledgements
+        /// removed per `purge_user` / `purge_user_contacts_batch`
+        #[pallet::constant]
+        type MaxPurgePerBlock: Get<u32>;
+
+        /// Maximum entries per map scanned by `verify_invariants`
+        #[pallet::constant]
+        type MaxInvariantScan: Get<u32>;
+
+        /// Largest ciphertext size an upload may declare
+        #[pallet::constant]
+        type MaxCiphertextBytes: Get<u64>;
     }
 
+    /// Current storage version (1: contacts stored as `ContactRecord` and
+    /// health records given a declared size, 2: legacy `ivs` pallet records imported, 3: contact weights,
+    /// 4: CID reference counts)
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// User profiles indexed by AccountId
@@ -85,14 +231,36 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Contact graph: user → list of contact AccountIds
+    /// Profile field visibility per user
+    #[pallet::storage]
+    #[pallet::getter(fn profile_visibility)]
+    pub type ProfileVisibilities<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        ProfileVisibility,
+        ValueQuery,
+    >;
+
+    /// Contact graph: user → list of timestamped contact records
     #[pallet::storage]
     #[pallet::getter(fn contacts)]
     pub type Contacts<T: Config> = StorageMap<
         _,
         Blake2_128Concat,
         T::AccountId,
-        BoundedVec<T::AccountId, T::MaxContacts>,
+        BoundedVec<ContactRecord<T::AccountId>, T::MaxContacts>,
+        ValueQuery,
+    >;
+
+    /// Number of contacts per user, kept in step with `Contacts`
+    #[pallet::storage]
+    #[pallet::getter(fn contact_degree)]
+    pub type ContactDegree<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u32,
         ValueQuery,
     >;
 
@@ -128,20 +296,192 @@ pub mod pallet {
     #[pallet::getter(fn user_count)]
     pub type UserCount<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Number of users with an encrypted health status on record
+    #[pallet::storage]
+    #[pallet::getter(fn health_reported_count)]
+    pub type HealthReportedCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Sum of declared ciphertext sizes across stored health records
+    #[pallet::storage]
+    #[pallet::getter(fn total_ciphertext_bytes)]
+    pub type TotalCiphertextBytes<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Blocks after which health records are purged (None = keep forever)
+    #[pallet::storage]
+    #[pallet::getter(fn retention_blocks)]
+    pub type RetentionBlocks<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Last account inspected by the retention sweep
+    #[pallet::storage]
+    pub type RetentionCursor<T: Config> = StorageValue<_, T::AccountId, OptionQuery>;
+
+    /// Runtime contact limit, at most `MaxContacts` (None = `MaxContacts`)
+    #[pallet::storage]
+    #[pallet::getter(fn contact_cap)]
+    pub type ContactCap<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    /// Display metadata for the disease tracked by this parachain
+    #[pallet::storage]
+    pub type DiseaseMetadataOf<T: Config> = StorageValue<_, DiseaseMetadata, OptionQuery>;
+
     /// Current joint public key identifier for encryption
     #[pallet::storage]
     #[pallet::getter(fn current_public_key)]
     pub type CurrentPublicKey<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>, ValueQuery>;
 
+    /// Diseases for which new health uploads are paused
+    #[pallet::storage]
+    #[pallet::getter(fn disease_upload_paused)]
+    pub type DiseaseUploadPaused<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<64>>,
+        (),
+        OptionQuery,
+    >;
+
+    /// Para id of the aggregator parachain this tracker reports to
+    #[pallet::storage]
+    #[pallet::getter(fn aggregator_para_id)]
+    pub type AggregatorParaId<T: Config> = StorageValue<_, u32, OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultVerboseEvents() -> bool {
+        true
+    }
+
+    /// Whether high-volume events are emitted.
+    ///
+    /// When `false`, `HealthStatusUploaded` and `IVSComputed` are suppressed;
+    /// registration and administrative events are always emitted.
+    #[pallet::storage]
+    #[pallet::getter(fn verbose_events)]
+    pub type VerboseEvents<T: Config> = StorageValue<_, bool, ValueQuery, DefaultVerboseEvents>;
+
+    /// Exposure notifications acknowledged by users: user → exposure ref → timestamp
+    #[pallet::storage]
+    pub type ExposureAcks<T: Config> = StorageDoubleMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<64>>,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Purged users whose inbound contact edges are still being removed,
+    /// with the last contact list scanned (`None` = not started)
+    #[pallet::storage]
+    #[pallet::getter(fn pending_contact_purge)]
+    pub type PendingContactPurges<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        Option<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Migrated accounts whose inbound contact edges are still being re-pointed:
+    /// old account → (new account, last contact list scanned, `None` = not started)
+    #[pallet::storage]
+    #[pallet::getter(fn pending_contact_migration)]
+    pub type PendingContactMigrations<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (T::AccountId, Option<T::AccountId>),
+        OptionQuery,
+    >;
+
+    /// New accounts of the migrations in `PendingContactMigrations`
+    #[pallet::storage]
+    pub type ContactMigrationTargets<T: Config> =
+        StorageMap<_, Blake2_128Concat, T::AccountId, (), OptionQuery>;
+
+    #[pallet::type_value]
+    pub fn DefaultEncryptionVersions() -> BoundedVec<u32, ConstU32<8>> {
+        BoundedVec::truncate_from(sp_std::vec![1])
+    }
+
+    /// Encryption scheme versions accepted on health uploads
+    #[pallet::storage]
+    #[pallet::getter(fn supported_encryption_versions)]
+    pub type SupportedEncryptionVersions<T: Config> =
+        StorageValue<_, BoundedVec<u32, ConstU32<8>>, ValueQuery, DefaultEncryptionVersions>;
+
+    /// Policy for users missing health data during an all-users recompute
+    #[pallet::storage]
+    #[pallet::getter(fn recompute_missing_policy)]
+    pub type RecomputeMissingPolicy<T: Config> = StorageValue<_, MissingDataPolicy, ValueQuery>;
+
+    /// Who may register users
+    #[pallet::storage]
+    #[pallet::getter(fn registration_mode)]
+    pub type CurrentRegistrationMode<T: Config> = StorageValue<_, RegistrationMode, ValueQuery>;
+
+    /// Accounts allowed to register while `RegistrationMode::Allowlist` is set
+    #[pallet::storage]
+    #[pallet::getter(fn registrar_allowed)]
+    pub type AllowedRegistrars<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Number of health and IVS records referencing each IPFS CID; a CID
+    /// is removed once nothing references it and `CidUnreferenced` is
+    /// emitted, so pinners can unpin it
+    #[pallet::storage]
+    pub type CidRefCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<128>>,
+        u32,
+        ValueQuery,
+    >;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Disease tracked by this parachain
+        pub disease_id: Vec<u8>,
+        /// Initial joint public key identifier
+        pub public_key: Vec<u8>,
+        #[serde(skip)]
+        pub _config: core::marker::PhantomData<T>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            let disease_bounded: BoundedVec<u8, ConstU32<64>> = self
+                .disease_id
+                .clone()
+                .try_into()
+                .expect("genesis disease_id is at most 64 bytes");
+            let key_bounded: BoundedVec<u8, ConstU32<128>> = self
+                .public_key
+                .clone()
+                .try_into()
+                .expect("genesis public_key is at most 128 bytes");
+
+            DiseaseId::<T>::put(disease_bounded);
+            CurrentPublicKey::<T>::put(key_bounded);
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// User registered with profile
-        UserRegistered { who: T::AccountId, name: Vec<u8> },
+        UserRegistered { who: T::AccountId, name: Vec<u8>, at: u64 },
         /// Contact added
-        ContactAdded { user: T::AccountId, contact: T::AccountId },
+        ContactAdded { user: T::AccountId, contact: T::AccountId, at: u64 },
         /// Encrypted health status uploaded
-        HealthStatusUploaded { who: T::AccountId, cid: Vec<u8>, disease_id: Vec<u8> },
+        HealthStatusUploaded { who: T::AccountId, cid: Vec<u8>, disease_id: Vec<u8>, at: u64 },
         /// Encrypted IVS computed and stored
         IVSComputed { who: T::AccountId, cid: Vec<u8>, computed_at: u64 },
         /// Disease ID set for this parachain
@@ -150,6 +490,78 @@ pub mod pallet {
         PublicKeyUpdated { key_id: Vec<u8> },
         /// User profile updated
         UserProfileUpdated { who: T::AccountId },
+        /// High-volume event emission toggled
+        VerboseEventsSet { enabled: bool },
+        /// Profile visibility updated
+        VisibilityUpdated { who: T::AccountId },
+        /// Aggregator parachain linkage set
+        AggregatorSet { para_id: u32 },
+        /// IVS computation requested after a health upload
+        IvsComputationRequested { who: T::AccountId, disease_id: Vec<u8> },
+        /// Retention period updated
+        RetentionPeriodSet { blocks: Option<u64> },
+        /// Health status purged after the retention period
+        HealthStatusExpired { who: T::AccountId },
+        /// User records moved to a new account
+        AccountMigrated { from: T::AccountId, to: T::AccountId },
+        /// Storage invariant check failed
+        InvariantViolation { code: InvariantCode },
+        /// Health uploads paused for a disease
+        DiseaseUploadsPaused { disease_id: Vec<u8> },
+        /// Health uploads resumed for a disease
+        DiseaseUploadsResumed { disease_id: Vec<u8> },
+        /// Unregistered accounts pruned from a contact list
+        ContactsCompacted { user: T::AccountId, removed: u32 },
+        /// User acknowledged an exposure notification
+        ExposureAcknowledged { who: T::AccountId, exposure_ref: Vec<u8> },
+        /// Missing-data policy for recomputes updated
+        RecomputeMissingPolicySet { policy: MissingDataPolicy },
+        /// Contact removed
+        ContactRemoved { user: T::AccountId, contact: T::AccountId },
+        /// Several contacts added in one call
+        ContactsBatchAdded { user: T::AccountId, count: u32 },
+        /// Accepted encryption scheme versions updated
+        EncryptionVersionsSet { versions: Vec<u32> },
+        /// Disease display metadata updated
+        DiseaseMetadataSet,
+        /// Runtime contact limit updated
+        ContactCapSet { cap: Option<u32> },
+        /// Weight of an existing contact changed
+        ContactWeightUpdated { user: T::AccountId, contact: T::AccountId, weight: u8 },
+        /// Registration mode updated
+        RegistrationModeSet { mode: RegistrationMode },
+        /// Account added to the registrar allow-list
+        RegistrarAllowed { account: T::AccountId },
+        /// Account removed from the registrar allow-list
+        RegistrarDisallowed { account: T::AccountId },
+        /// Encrypted health status overwritten by a new upload
+        HealthStatusReplaced {
+            who: T::AccountId,
+            old_cid: Vec<u8>,
+            new_cid: Vec<u8>,
+            disease_id: Vec<u8>,
+        },
+        /// Chain attested to holding no data about an account
+        NoDataAttested { subject: T::AccountId, at: u64 },
+        /// User deactivated their profile
+        UserDeactivated { who: T::AccountId },
+        /// User reactivated their profile
+        UserReactivated { who: T::AccountId },
+        /// All records of a user erased
+        UserPurged { user: T::AccountId },
+        /// Inbound contact edges to a purged user removed; `complete` once
+        /// every contact list has been scanned
+        PurgedContactsRemoved { user: T::AccountId, removed: u32, complete: bool },
+        /// Inbound contact edges to a migrated account re-pointed at its new
+        /// account; `complete` once every contact list has been scanned
+        MigratedContactsRewritten {
+            from: T::AccountId,
+            to: T::AccountId,
+            rewritten: u32,
+            complete: bool,
+        },
+        /// No health or IVS record references this CID any more
+        CidUnreferenced { cid: Vec<u8> },
     }
 
     #[pallet::error]
@@ -162,12 +574,45 @@ pub mod pallet {
         TooManyContacts,
         /// Contact already exists
         ContactAlreadyExists,
+        /// Contact is not in the user's list
+        ContactNotFound,
         /// Invalid CID format
         InvalidCID,
         /// Invalid disease ID
         InvalidDiseaseId,
         /// Not authorized
         NotAuthorized,
+        /// Uploads for this disease are paused
+        DiseasePaused,
+        /// Exposure reference is too long
+        InvalidExposureRef,
+        /// Declared ciphertext size exceeds `MaxCiphertextBytes`
+        CiphertextTooLarge,
+        /// The chain holds data about the account
+        DataExists,
+        /// No contact purge is pending for the account
+        NoPendingPurge,
+        /// A contact migration involving the account is still in progress
+        MigrationPending,
+        /// No contact migration is pending for the account
+        NoPendingMigration,
+        /// Encryption scheme version is not accepted
+        UnsupportedEncryptionVersion,
+        /// Too many encryption scheme versions
+        TooManyEncryptionVersions,
+        /// Disease metadata field is too long
+        InvalidDiseaseMetadata,
+        /// Contact cap exceeds `MaxContacts`
+        ContactCapTooHigh,
+        /// A user cannot add themselves as a contact
+        SelfContactNotAllowed,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::purge_expired_health()
+        }
     }
 
     #[pallet::call]
@@ -182,7 +627,16 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            ensure!(
+                CurrentRegistrationMode::<T>::get() == RegistrationMode::Open
+                    || AllowedRegistrars::<T>::contains_key(&who),
+                Error::<T>::NotAuthorized
+            );
             ensure!(!UserProfiles::<T>::contains_key(&who), Error::<T>::UserAlreadyExists);
+            ensure!(
+                !PendingContactMigrations::<T>::contains_key(&who),
+                Error::<T>::MigrationPending
+            );
 
             let name_bounded: BoundedVec<u8, ConstU32<64>> = 
                 name.clone().try_into().map_err(|_| Error::<T>::InvalidDiseaseId)?;
@@ -199,7 +653,11 @@ pub mod pallet {
             UserProfiles::<T>::insert(&who, profile);
             UserCount::<T>::mutate(|count| *count = count.saturating_add(1));
 
-            Self::deposit_event(Event::UserRegistered { who, name });
+            Self::deposit_event(Event::UserRegistered {
+                who,
+                name,
+                at: Self::current_timestamp(),
+            });
             Ok(())
         }
 
@@ -212,56 +670,31 @@ pub mod pallet {
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
-
-            Contacts::<T>::try_mutate(&who, |contacts| {
-                ensure!(!contacts.contains(&contact), Error::<T>::ContactAlreadyExists);
-                contacts.try_push(contact.clone())
-                    .map_err(|_| Error::<T>::TooManyContacts)?;
-                Ok::<(), Error<T>>(())
-            })?;
-
-            Self::deposit_event(Event::ContactAdded { user: who, contact });
-            Ok(())
+            Self::do_add_contact(who, contact, 0, DEFAULT_CONTACT_WEIGHT)
         }
 
-        /// Upload encrypted health status (CID from IPFS)
+        /// Upload encrypted health status (CID from IPFS).
+        ///
+        /// `size_bytes` is the ciphertext size declared by the uploader and may
+        /// not exceed `MaxCiphertextBytes`.
         #[pallet::call_index(2)]
         #[pallet::weight(10_000)]
         pub fn upload_encrypted_health(
             origin: OriginFor<T>,
             cid: Vec<u8>,
             disease_id: Vec<u8>,
+            version: u32,
+            size_bytes: u64,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
-            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
-
-            let cid_bounded: BoundedVec<u8, ConstU32<128>> = 
-                cid.clone().try_into().map_err(|_| Error::<T>::InvalidCID)?;
-            let disease_bounded: BoundedVec<u8, ConstU32<64>> = 
-                disease_id.clone().try_into().map_err(|_| Error::<T>::InvalidDiseaseId)?;
-            let pk_id = CurrentPublicKey::<T>::get();
-
-            let health_status = EncryptedHealthStatus {
-                cid: cid_bounded,
-                disease_id: disease_bounded,
-                uploaded_at: Self::current_timestamp(),
-                encryption_version: 1,
-                public_key_id: pk_id,
-            };
-
-            EncryptedHealthStatuses::<T>::insert(&who, health_status);
-
-            Self::deposit_event(Event::HealthStatusUploaded { 
-                who, 
-                cid, 
-                disease_id 
-            });
-            Ok(())
+            Self::do_upload_encrypted_health(who, cid, disease_id, size_bytes, version)
         }
 
-        /// Store encrypted IVS score (called by authorized compute network)
+        /// Store encrypted IVS score (called by authorized compute network).
+        ///
+        /// Gated on `ComputeOrigin`; the origin check adds no storage access
+        /// with `EnsureRoot`, so the weight is unchanged.
         #[pallet::call_index(3)]
         #[pallet::weight(10_000)]
         pub fn store_encrypted_ivs(
@@ -270,12 +703,11 @@ pub mod pallet {
             cid: Vec<u8>,
             parameters: Vec<u8>,
         ) -> DispatchResult {
-            ensure_root(origin)?;
+            T::ComputeOrigin::ensure_origin(origin)?;
 
             ensure!(UserProfiles::<T>::contains_key(&user), Error::<T>::UserNotFound);
 
-            let cid_bounded: BoundedVec<u8, ConstU32<128>> = 
-                cid.clone().try_into().map_err(|_| Error::<T>::InvalidCID)?;
+            let cid_bounded = Self::bound_cid(cid.clone())?;
             let params_bounded: BoundedVec<u8, ConstU32<128>> = 
                 parameters.try_into().map_err(|_| Error::<T>::InvalidDiseaseId)?;
 
@@ -286,13 +718,19 @@ pub mod pallet {
                 parameters: params_bounded,
             };
 
+            Self::retain_cid(&ivs_record.cid);
+            if let Some(previous) = EncryptedIVSScores::<T>::get(&user) {
+                Self::release_cid(&previous.cid);
+            }
             EncryptedIVSScores::<T>::insert(&user, ivs_record);
 
-            Self::deposit_event(Event::IVSComputed { 
-                who: user, 
-                cid, 
-                computed_at 
-            });
+            if VerboseEvents::<T>::get() {
+                Self::deposit_event(Event::IVSComputed { 
+                    who: user, 
+                    cid, 
+                    computed_at 
+                });
+            }
             Ok(())
         }
 
@@ -354,27 +792,1265 @@ pub mod pallet {
             Self::deposit_event(Event::UserProfileUpdated { who });
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Get current timestamp (block number as proxy)
-        fn current_timestamp() -> u64 {
-            <frame_system::Pallet<T>>::block_number().saturated_into::<u64>()
+        /// Enable or disable high-volume events (admin only)
+        #[pallet::call_index(7)]
+        #[pallet::weight(10_000)]
+        pub fn set_verbose_events(
+            origin: OriginFor<T>,
+            enabled: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            VerboseEvents::<T>::put(enabled);
+
+            Self::deposit_event(Event::VerboseEventsSet { enabled });
+            Ok(())
         }
 
-        /// Get all contacts for a user
-        pub fn get_user_contacts(who: &T::AccountId) -> Vec<T::AccountId> {
-            Contacts::<T>::get(who).to_vec()
+        /// Choose which profile fields are publicly readable
+        #[pallet::call_index(8)]
+        #[pallet::weight(10_000)]
+        pub fn set_visibility(
+            origin: OriginFor<T>,
+            visibility: ProfileVisibility,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
+
+            ProfileVisibilities::<T>::insert(&who, visibility);
+
+            Self::deposit_event(Event::VisibilityUpdated { who });
+            Ok(())
         }
 
-        /// Check if user has uploaded encrypted health status
-        pub fn has_health_status(who: &T::AccountId) -> bool {
-            EncryptedHealthStatuses::<T>::contains_key(who)
+        /// Set the aggregator parachain this tracker reports to (admin only)
+        #[pallet::call_index(9)]
+        #[pallet::weight(10_000)]
+        pub fn set_aggregator(
+            origin: OriginFor<T>,
+            para_id: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            AggregatorParaId::<T>::put(para_id);
+
+            Self::deposit_event(Event::AggregatorSet { para_id });
+            Ok(())
         }
 
-        /// Get all registered users (for iteration)
-        pub fn get_all_users() -> Vec<T::AccountId> {
-            UserProfiles::<T>::iter_keys().collect()
+        /// Upload encrypted health status and request an IVS computation
+        /// in one transaction; nothing is stored if the upload is rejected.
+        ///
+        /// Takes the same arguments as `upload_encrypted_health`.
+        #[pallet::call_index(10)]
+        #[pallet::weight(10_000)]
+        pub fn upload_and_request_ivs(
+            origin: OriginFor<T>,
+            cid: Vec<u8>,
+            disease_id: Vec<u8>,
+            version: u32,
+            size_bytes: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_upload_encrypted_health(
+                who.clone(),
+                cid,
+                disease_id.clone(),
+                size_bytes,
+                version,
+            )?;
+
+            Self::deposit_event(Event::IvsComputationRequested { who, disease_id });
+            Ok(())
+        }
+
+        /// Set the health-data retention period in blocks; `None` disables purging
+        #[pallet::call_index(11)]
+        #[pallet::weight(10_000)]
+        pub fn set_retention_period(
+            origin: OriginFor<T>,
+            blocks: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match blocks {
+                Some(blocks) => RetentionBlocks::<T>::put(blocks),
+                None => {
+                    RetentionBlocks::<T>::kill();
+                    RetentionCursor::<T>::kill();
+                },
+            }
+
+            Self::deposit_event(Event::RetentionPeriodSet { blocks });
+            Ok(())
+        }
+
+        /// Move the caller's profile, contacts, health status, IVS and exposure
+        /// acknowledgements to a new account, which must hold no data.
+        ///
+        /// Edges in the lists of the caller's own contacts are re-pointed at
+        /// `new_account` here, which is bounded by `MaxContacts`, and up to
+        /// `MaxPurgePerBlock` acknowledgements are moved. Edges from accounts the
+        /// caller never listed need a scan of the whole graph, so they and any
+        /// remaining acknowledgements are queued for
+        /// `migrate_account_contacts_batch`. A list that already holds
+        /// `new_account` drops the old edge rather than gaining a duplicate.
+        /// Until the queue drains, neither account can migrate again and the old
+        /// one cannot re-register.
+        ///
+        /// Weighted for a full contact list and a full batch of acknowledgements.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::migrate_account(
+            T::MaxContacts::get(),
+            T::MaxPurgePerBlock::get(),
+        ))]
+        pub fn migrate_account(
+            origin: OriginFor<T>,
+            new_account: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
+            ensure!(!Self::has_any_data(&new_account), Error::<T>::UserAlreadyExists);
+            ensure!(
+                !ContactMigrationTargets::<T>::contains_key(&who)
+                    && !PendingContactMigrations::<T>::contains_key(&new_account),
+                Error::<T>::MigrationPending
+            );
+
+            let profile = UserProfiles::<T>::take(&who).ok_or(Error::<T>::UserNotFound)?;
+            UserProfiles::<T>::insert(&new_account, profile);
+            ProfileVisibilities::<T>::insert(&new_account, ProfileVisibilities::<T>::take(&who));
+            if let Some(health) = EncryptedHealthStatuses::<T>::take(&who) {
+                EncryptedHealthStatuses::<T>::insert(&new_account, health);
+            }
+            if let Some(ivs) = EncryptedIVSScores::<T>::take(&who) {
+                EncryptedIVSScores::<T>::insert(&new_account, ivs);
+            }
+
+            let mut contacts = Contacts::<T>::take(&who);
+            ContactDegree::<T>::remove(&who);
+            contacts.retain(|record| record.contact != new_account);
+            for record in contacts.iter() {
+                Self::rewrite_contact_edges(&record.contact, &who, Some(&new_account));
+            }
+            Self::set_contact_degree(&new_account, contacts.len());
+            Contacts::<T>::insert(&new_account, contacts);
+            Self::drain_exposure_acks(&who, Some(&new_account));
+
+            PendingContactMigrations::<T>::insert(&who, (new_account.clone(), None::<T::AccountId>));
+            ContactMigrationTargets::<T>::insert(&new_account, ());
+
+            Self::deposit_event(Event::AccountMigrated { from: who, to: new_account });
+            Ok(())
+        }
+
+        /// Check maintained counters against a bounded recount of storage,
+        /// emitting `InvariantViolation` for each mismatch. Read-only.
+        #[pallet::call_index(13)]
+        #[pallet::weight(10_000)]
+        pub fn verify_invariants(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let cap = T::MaxInvariantScan::get() as usize;
+
+            let users = UserProfiles::<T>::iter_keys().take(cap + 1).count();
+            if users <= cap && users as u32 != UserCount::<T>::get() {
+                Self::deposit_event(Event::InvariantViolation {
+                    code: InvariantCode::UserCountMismatch,
+                });
+            }
+
+            let reported = EncryptedHealthStatuses::<T>::iter_keys().take(cap + 1).count();
+            if reported <= cap && reported as u32 != HealthReportedCount::<T>::get() {
+                Self::deposit_event(Event::InvariantViolation {
+                    code: InvariantCode::HealthReportedCountMismatch,
+                });
+            }
+
+            Ok(())
+        }
+
+        /// Stop accepting health uploads for a disease (admin only)
+        #[pallet::call_index(14)]
+        #[pallet::weight(10_000)]
+        pub fn pause_disease_uploads(
+            origin: OriginFor<T>,
+            disease_id: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let disease_bounded: BoundedVec<u8, ConstU32<64>> = 
+                disease_id.clone().try_into().map_err(|_| Error::<T>::InvalidDiseaseId)?;
+
+            DiseaseUploadPaused::<T>::insert(disease_bounded, ());
+
+            Self::deposit_event(Event::DiseaseUploadsPaused { disease_id });
+            Ok(())
+        }
+
+        /// Resume health uploads for a disease (admin only)
+        #[pallet::call_index(15)]
+        #[pallet::weight(10_000)]
+        pub fn unpause_disease_uploads(
+            origin: OriginFor<T>,
+            disease_id: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let disease_bounded: BoundedVec<u8, ConstU32<64>> = 
+                disease_id.clone().try_into().map_err(|_| Error::<T>::InvalidDiseaseId)?;
+
+            DiseaseUploadPaused::<T>::remove(disease_bounded);
+
+            Self::deposit_event(Event::DiseaseUploadsResumed { disease_id });
+            Ok(())
+        }
+
+        /// Drop contacts that are no longer registered users, reclaiming
+        /// capacity in the user's contact list (admin only)
+        #[pallet::call_index(16)]
+        #[pallet::weight(10_000)]
+        pub fn compact_contacts(
+            origin: OriginFor<T>,
+            user: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let removed = Contacts::<T>::mutate(&user, |contacts| {
+                let before = contacts.len();
+                contacts.retain(|record| UserProfiles::<T>::contains_key(&record.contact));
+                Self::set_contact_degree(&user, contacts.len());
+                (before - contacts.len()) as u32
+            });
+
+            Self::deposit_event(Event::ContactsCompacted { user, removed });
+            Ok(())
+        }
+
+        /// Record that the caller acknowledged an exposure notification
+        #[pallet::call_index(17)]
+        #[pallet::weight(10_000)]
+        pub fn acknowledge_exposure(
+            origin: OriginFor<T>,
+            exposure_ref: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
+
+            let ref_bounded: BoundedVec<u8, ConstU32<64>> = 
+                exposure_ref.clone().try_into().map_err(|_| Error::<T>::InvalidExposureRef)?;
+
+            ExposureAcks::<T>::insert(&who, ref_bounded, Self::current_timestamp());
+
+            Self::deposit_event(Event::ExposureAcknowledged { who, exposure_ref });
+            Ok(())
+        }
+
+        /// Set how recomputes treat users without health data (admin only)
+        #[pallet::call_index(18)]
+        #[pallet::weight(10_000)]
+        pub fn set_recompute_missing_policy(
+            origin: OriginFor<T>,
+            policy: MissingDataPolicy,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RecomputeMissingPolicy::<T>::put(policy.clone());
+
+            Self::deposit_event(Event::RecomputeMissingPolicySet { policy });
+            Ok(())
+        }
+
+        /// Remove a contact from user's contact list
+        #[pallet::call_index(19)]
+        #[pallet::weight(10_000)]
+        pub fn remove_contact(
+            origin: OriginFor<T>,
+            contact: T::AccountId,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Contacts::<T>::try_mutate(&who, |contacts| {
+                let index = contacts
+                    .iter()
+                    .position(|record| record.contact == contact)
+                    .ok_or(Error::<T>::ContactNotFound)?;
+                contacts.remove(index);
+                Self::set_contact_degree(&who, contacts.len());
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::ContactRemoved { user: who, contact });
+            Ok(())
+        }
+
+        /// Add a contact recording the current block, how long it lasted and
+        /// how intense it was
+        #[pallet::call_index(20)]
+        #[pallet::weight(10_000)]
+        pub fn add_contact_with_time(
+            origin: OriginFor<T>,
+            contact: T::AccountId,
+            duration_blocks: u32,
+            weight: u8,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::do_add_contact(who, contact, duration_blocks, weight)
+        }
+
+        /// Attest that the chain holds no data about `subject`, emitting an
+        /// event a light client can reference (admin only)
+        #[pallet::call_index(21)]
+        #[pallet::weight(10_000)]
+        pub fn attest_no_data(
+            origin: OriginFor<T>,
+            subject: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(!Self::has_any_data(&subject), Error::<T>::DataExists);
+
+            Self::deposit_event(Event::NoDataAttested {
+                subject,
+                at: Self::current_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Deactivate the caller's profile, opting out of future IVS
+        /// computation while keeping historical records
+        #[pallet::call_index(22)]
+        #[pallet::weight(10_000)]
+        pub fn deactivate_profile(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::set_profile_active(&who, false)?;
+
+            Self::deposit_event(Event::UserDeactivated { who });
+            Ok(())
+        }
+
+        /// Reactivate the caller's profile
+        #[pallet::call_index(23)]
+        #[pallet::weight(10_000)]
+        pub fn reactivate_profile(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Self::set_profile_active(&who, true)?;
+
+            Self::deposit_event(Event::UserReactivated { who });
+            Ok(())
+        }
+
+        /// Erase every record of a user (admin only).
+        ///
+        /// Edges in the lists of the user's own contacts are removed here, which
+        /// is bounded by `MaxContacts`, as are up to `MaxPurgePerBlock` exposure
+        /// acknowledgements. Edges from accounts the user never listed need a
+        /// scan of the whole graph, so they and any remaining acknowledgements
+        /// are queued for `purge_user_contacts_batch`.
+        ///
+        /// Weighted for a full contact list and a full batch of acknowledgements.
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::purge_user(
+            T::MaxContacts::get(),
+            T::MaxPurgePerBlock::get(),
+        ))]
+        pub fn purge_user(
+            origin: OriginFor<T>,
+            user: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            ensure!(Self::has_any_data(&user), Error::<T>::UserNotFound);
+
+            if UserProfiles::<T>::take(&user).is_some() {
+                UserCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+            }
+            ProfileVisibilities::<T>::remove(&user);
+            if let Some(health) = EncryptedHealthStatuses::<T>::take(&user) {
+                HealthReportedCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                TotalCiphertextBytes::<T>::mutate(|total| {
+                    *total = total.saturating_sub(health.size_bytes)
+                });
+                Self::release_cid(&health.cid);
+            }
+            if let Some(ivs) = EncryptedIVSScores::<T>::take(&user) {
+                Self::release_cid(&ivs.cid);
+            }
+
+            ContactDegree::<T>::remove(&user);
+            for record in Contacts::<T>::take(&user).iter() {
+                Self::rewrite_contact_edges(&record.contact, &user, None);
+            }
+            Self::drain_exposure_acks(&user, None);
+            PendingContactPurges::<T>::insert(&user, None::<T::AccountId>);
+
+            Self::deposit_event(Event::UserPurged { user });
+            Ok(())
+        }
+
+        /// Remove a purged user from up to `MaxPurgePerBlock` other contact
+        /// lists, resuming where the previous batch stopped (admin only).
+        ///
+        /// Exposure acknowledgements left over by `purge_user` are removed first,
+        /// `MaxPurgePerBlock` at a time; the contact scan continues once none
+        /// remain. Weighted for a full batch of both.
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::purge_user_contacts_batch(
+            T::MaxPurgePerBlock::get(),
+            T::MaxPurgePerBlock::get(),
+        ))]
+        pub fn purge_user_contacts_batch(
+            origin: OriginFor<T>,
+            user: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let cursor =
+                PendingContactPurges::<T>::get(&user).ok_or(Error::<T>::NoPendingPurge)?;
+
+            if Self::drain_exposure_acks(&user, None) {
+                Self::deposit_event(Event::PurgedContactsRemoved {
+                    user,
+                    removed: 0,
+                    complete: false,
+                });
+                return Ok(());
+            }
+
+            let (removed, next) = Self::rewrite_inbound_edges(&user, None, cursor);
+            let complete = next.is_none();
+            match next {
+                Some(last) => PendingContactPurges::<T>::insert(&user, Some(last)),
+                None => PendingContactPurges::<T>::remove(&user),
+            }
+
+            Self::deposit_event(Event::PurgedContactsRemoved { user, removed, complete });
+            Ok(())
+        }
+
+        /// Add several contacts at once, skipping ones already on the list.
+        ///
+        /// At most `MaxContacts` accounts may be passed; each is checked against
+        /// the whole list, so the weight scales with their number.
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::add_contacts_batch(contacts.len() as u32))]
+        pub fn add_contacts_batch(
+            origin: OriginFor<T>,
+            contacts: Vec<T::AccountId>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            ensure!(
+                contacts.len() as u32 <= T::MaxContacts::get(),
+                Error::<T>::TooManyContacts
+            );
+            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
+
+            let contacted_at = Self::current_timestamp();
+            let cap = Self::effective_contact_cap() as usize;
+            let count = Contacts::<T>::try_mutate(&who, |existing| {
+                let before = existing.len();
+                for contact in contacts {
+                    ensure!(contact != who, Error::<T>::SelfContactNotAllowed);
+                    if existing.iter().any(|record| record.contact == contact) {
+                        continue;
+                    }
+                    ensure!(existing.len() < cap, Error::<T>::TooManyContacts);
+                    let record = ContactRecord {
+                        contact,
+                        contacted_at,
+                        duration_blocks: 0,
+                        weight: DEFAULT_CONTACT_WEIGHT,
+                    };
+                    existing.try_push(record).map_err(|_| Error::<T>::TooManyContacts)?;
+                }
+                Self::set_contact_degree(&who, existing.len());
+                Ok::<u32, Error<T>>((existing.len() - before) as u32)
+            })?;
+
+            Self::deposit_event(Event::ContactsBatchAdded { user: who, count });
+            Ok(())
+        }
+
+        /// Set the encryption scheme versions accepted on health uploads
+        /// (admin only)
+        #[pallet::call_index(27)]
+        #[pallet::weight(10_000)]
+        pub fn set_supported_encryption_versions(
+            origin: OriginFor<T>,
+            versions: Vec<u32>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let versions_bounded: BoundedVec<u32, ConstU32<8>> = versions
+                .clone()
+                .try_into()
+                .map_err(|_| Error::<T>::TooManyEncryptionVersions)?;
+
+            SupportedEncryptionVersions::<T>::put(versions_bounded);
+
+            Self::deposit_event(Event::EncryptionVersionsSet { versions });
+            Ok(())
+        }
+
+        /// Set display name, description and ICD-10 code of the tracked
+        /// disease (admin only)
+        #[pallet::call_index(28)]
+        #[pallet::weight(10_000)]
+        pub fn set_disease_metadata(
+            origin: OriginFor<T>,
+            display_name: Vec<u8>,
+            description: Vec<u8>,
+            icd_code: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let metadata = DiseaseMetadata {
+                display_name: display_name
+                    .try_into()
+                    .map_err(|_| Error::<T>::InvalidDiseaseMetadata)?,
+                description: description
+                    .try_into()
+                    .map_err(|_| Error::<T>::InvalidDiseaseMetadata)?,
+                icd_code: icd_code.try_into().map_err(|_| Error::<T>::InvalidDiseaseMetadata)?,
+            };
+
+            DiseaseMetadataOf::<T>::put(metadata);
+
+            Self::deposit_event(Event::DiseaseMetadataSet);
+            Ok(())
+        }
+
+        /// Set the per-user contact limit below `MaxContacts`; `None` reverts to
+        /// `MaxContacts` (admin only). Existing lists above the limit are kept.
+        #[pallet::call_index(29)]
+        #[pallet::weight(10_000)]
+        pub fn set_contact_cap(
+            origin: OriginFor<T>,
+            cap: Option<u32>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            match cap {
+                Some(cap) => {
+                    ensure!(cap <= T::MaxContacts::get(), Error::<T>::ContactCapTooHigh);
+                    ContactCap::<T>::put(cap);
+                },
+                None => ContactCap::<T>::kill(),
+            }
+
+            Self::deposit_event(Event::ContactCapSet { cap });
+            Ok(())
+        }
+
+        /// Change the weight of an existing contact in place
+        #[pallet::call_index(30)]
+        #[pallet::weight(10_000)]
+        pub fn update_contact_weight(
+            origin: OriginFor<T>,
+            contact: T::AccountId,
+            weight: u8,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            Contacts::<T>::try_mutate(&who, |contacts| {
+                let record = contacts
+                    .iter_mut()
+                    .find(|record| record.contact == contact)
+                    .ok_or(Error::<T>::ContactNotFound)?;
+                record.weight = weight;
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::ContactWeightUpdated { user: who, contact, weight });
+            Ok(())
+        }
+
+        /// Set who may register users (admin only)
+        #[pallet::call_index(31)]
+        #[pallet::weight(10_000)]
+        pub fn set_registration_mode(
+            origin: OriginFor<T>,
+            mode: RegistrationMode,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            CurrentRegistrationMode::<T>::put(mode.clone());
+
+            Self::deposit_event(Event::RegistrationModeSet { mode });
+            Ok(())
+        }
+
+        /// Allow an account to register while in `RegistrationMode::Allowlist`
+        /// (admin only)
+        #[pallet::call_index(32)]
+        #[pallet::weight(10_000)]
+        pub fn add_allowed_registrar(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            AllowedRegistrars::<T>::insert(&account, ());
+
+            Self::deposit_event(Event::RegistrarAllowed { account });
+            Ok(())
+        }
+
+        /// Remove an account from the registrar allow-list (admin only)
+        #[pallet::call_index(33)]
+        #[pallet::weight(10_000)]
+        pub fn remove_allowed_registrar(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            AllowedRegistrars::<T>::remove(&account);
+
+            Self::deposit_event(Event::RegistrarDisallowed { account });
+            Ok(())
+        }
+
+        /// Re-point a migrated account's inbound edges in up to
+        /// `MaxPurgePerBlock` other contact lists, resuming where the previous
+        /// batch stopped. Any signed account may advance a migration.
+        ///
+        /// Exposure acknowledgements left over by `migrate_account` are moved
+        /// first, `MaxPurgePerBlock` at a time; the contact scan continues once
+        /// none remain. If the new account has since been purged, the edges and
+        /// acknowledgements are removed instead. Weighted for a full batch of both.
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::migrate_account_contacts_batch(
+            T::MaxPurgePerBlock::get(),
+            T::MaxPurgePerBlock::get(),
+        ))]
+        pub fn migrate_account_contacts_batch(
+            origin: OriginFor<T>,
+            from: T::AccountId,
+        ) -> DispatchResult {
+            ensure_signed(origin)?;
+
+            let (to, cursor) =
+                PendingContactMigrations::<T>::get(&from).ok_or(Error::<T>::NoPendingMigration)?;
+            let target = UserProfiles::<T>::contains_key(&to).then_some(&to);
+
+            if Self::drain_exposure_acks(&from, target) {
+                Self::deposit_event(Event::MigratedContactsRewritten {
+                    from,
+                    to,
+                    rewritten: 0,
+                    complete: false,
+                });
+                return Ok(());
+            }
+
+            let (rewritten, next) = Self::rewrite_inbound_edges(&from, target, cursor);
+            let complete = next.is_none();
+            match next {
+                Some(last) => PendingContactMigrations::<T>::insert(&from, (to.clone(), Some(last))),
+                None => {
+                    PendingContactMigrations::<T>::remove(&from);
+                    ContactMigrationTargets::<T>::remove(&to);
+                },
+            }
+
+            Self::deposit_event(Event::MigratedContactsRewritten { from, to, rewritten, complete });
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Get current timestamp (block number as proxy)
+        fn current_timestamp() -> u64 {
+            <frame_system::Pallet<T>>::block_number().saturated_into::<u64>()
+        }
+
+        fn set_profile_active(who: &T::AccountId, is_active: bool) -> DispatchResult {
+            UserProfiles::<T>::try_mutate(who, |maybe_profile| {
+                let profile = maybe_profile.as_mut().ok_or(Error::<T>::UserNotFound)?;
+                profile.is_active = is_active;
+                Ok(())
+            })
+        }
+
+        /// Check a CID is well formed and matches the configured `AllowedCidPrefixes`
+        pub fn validate_cid(bytes: &[u8]) -> bool {
+            ivs_primitives::is_well_formed_cid(bytes)
+                && T::AllowedCidPrefixes::get()
+                    .iter()
+                    .any(|prefix| bytes.starts_with(prefix))
+        }
+
+        /// Validate a CID and convert it into its bounded storage form
+        fn bound_cid(cid: Vec<u8>) -> Result<BoundedVec<u8, ConstU32<128>>, Error<T>> {
+            ensure!(Self::validate_cid(&cid), Error::<T>::InvalidCID);
+            cid.try_into().map_err(|_| Error::<T>::InvalidCID)
+        }
+
+        /// Move up to `MaxPurgePerBlock` of `from`'s exposure acknowledgements
+        /// to `to`, or drop them if `to` is `None`, returning whether any remain
+        fn drain_exposure_acks(from: &T::AccountId, to: Option<&T::AccountId>) -> bool {
+            let mut acks = ExposureAcks::<T>::iter_prefix(from);
+            let batch: Vec<_> = acks.by_ref().take(T::MaxPurgePerBlock::get() as usize).collect();
+            let remaining = acks.next().is_some();
+            for (exposure_ref, at) in batch {
+                ExposureAcks::<T>::remove(from, &exposure_ref);
+                if let Some(to) = to {
+                    ExposureAcks::<T>::insert(to, exposure_ref, at);
+                }
+            }
+            remaining
+        }
+
+        /// Re-point `owner`'s edges to `from` at `to`, removing them instead if
+        /// `to` is `None`, is `owner` or is already listed; returns the number of
+        /// edges changed
+        fn rewrite_contact_edges(
+            owner: &T::AccountId,
+            from: &T::AccountId,
+            to: Option<&T::AccountId>,
+        ) -> u32 {
+            Contacts::<T>::mutate(owner, |contacts| {
+                let to = to.filter(|to| {
+                    *to != owner && !contacts.iter().any(|record| &record.contact == *to)
+                });
+                match to {
+                    Some(to) => {
+                        let mut rewritten: u32 = 0;
+                        for entry in contacts.iter_mut().filter(|entry| &entry.contact == from) {
+                            entry.contact = to.clone();
+                            rewritten = rewritten.saturating_add(1);
+                        }
+                        rewritten
+                    },
+                    None => {
+                        let before = contacts.len();
+                        contacts.retain(|record| &record.contact != from);
+                        Self::set_contact_degree(owner, contacts.len());
+                        (before - contacts.len()) as u32
+                    },
+                }
+            })
+        }
+
+        /// Apply `rewrite_contact_edges` to up to `MaxPurgePerBlock` contact lists
+        /// after `cursor`, returning the edges changed and the cursor to resume
+        /// from (`None` once every list has been scanned)
+        fn rewrite_inbound_edges(
+            from: &T::AccountId,
+            to: Option<&T::AccountId>,
+            cursor: Option<T::AccountId>,
+        ) -> (u32, Option<T::AccountId>) {
+            let mut iter = match cursor {
+                Some(account) => {
+                    Contacts::<T>::iter_keys_from(Contacts::<T>::hashed_key_for(account))
+                },
+                None => Contacts::<T>::iter_keys(),
+            };
+
+            let mut scanned = Vec::new();
+            for who in iter.by_ref().take(T::MaxPurgePerBlock::get() as usize) {
+                scanned.push(who);
+            }
+            let complete = iter.next().is_none();
+
+            let mut changed: u32 = 0;
+            for owner in scanned.iter() {
+                changed = changed.saturating_add(Self::rewrite_contact_edges(owner, from, to));
+            }
+
+            let next = if complete { None } else { scanned.pop() };
+            (changed, next)
+        }
+
+        /// Count one more record referencing `cid`
+        fn retain_cid(cid: &BoundedVec<u8, ConstU32<128>>) {
+            CidRefCount::<T>::mutate(cid, |count| *count = count.saturating_add(1));
+        }
+
+        /// Count one fewer record referencing `cid`, dropping it and emitting
+        /// `CidUnreferenced` at zero
+        fn release_cid(cid: &BoundedVec<u8, ConstU32<128>>) {
+            let released = CidRefCount::<T>::mutate_exists(cid, |count| {
+                let was_referenced = count.is_some();
+                *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+                was_referenced && count.is_none()
+            });
+            if released {
+                Self::deposit_event(Event::CidUnreferenced { cid: cid.to_vec() });
+            }
+        }
+
+        /// Number of health and IVS records referencing `cid`
+        pub fn get_cid_refcount(cid: Vec<u8>) -> u32 {
+            BoundedVec::<u8, ConstU32<128>>::try_from(cid)
+                .map_or(0, |cid| CidRefCount::<T>::get(cid))
+        }
+
+        /// Contact limit in force: `ContactCap` if set, bounded by `MaxContacts`
+        fn effective_contact_cap() -> u32 {
+            let max = T::MaxContacts::get();
+            ContactCap::<T>::get().map_or(max, |cap| cap.min(max))
+        }
+
+        /// Record a user's contact count, dropping the entry when it reaches zero
+        fn set_contact_degree(who: &T::AccountId, degree: usize) {
+            if degree == 0 {
+                ContactDegree::<T>::remove(who);
+            } else {
+                ContactDegree::<T>::insert(who, degree as u32);
+            }
+        }
+
+        /// Append a contact record stamped with the current block
+        fn do_add_contact(
+            who: T::AccountId,
+            contact: T::AccountId,
+            duration_blocks: u32,
+            weight: u8,
+        ) -> DispatchResult {
+            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
+            ensure!(contact != who, Error::<T>::SelfContactNotAllowed);
+
+            Contacts::<T>::try_mutate(&who, |contacts| {
+                ensure!(
+                    !contacts.iter().any(|record| record.contact == contact),
+                    Error::<T>::ContactAlreadyExists
+                );
+                ensure!(
+                    (contacts.len() as u32) < Self::effective_contact_cap(),
+                    Error::<T>::TooManyContacts
+                );
+                let record = ContactRecord {
+                    contact: contact.clone(),
+                    contacted_at: Self::current_timestamp(),
+                    duration_blocks,
+                    weight,
+                };
+                contacts.try_push(record)
+                    .map_err(|_| Error::<T>::TooManyContacts)?;
+                Self::set_contact_degree(&who, contacts.len());
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::ContactAdded {
+                user: who,
+                contact,
+                at: Self::current_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Validate and store an encrypted health status upload
+        fn do_upload_encrypted_health(
+            who: T::AccountId,
+            cid: Vec<u8>,
+            disease_id: Vec<u8>,
+            size_bytes: u64,
+            version: u32,
+        ) -> DispatchResult {
+            ensure!(UserProfiles::<T>::contains_key(&who), Error::<T>::UserNotFound);
+            ensure!(size_bytes <= T::MaxCiphertextBytes::get(), Error::<T>::CiphertextTooLarge);
+            ensure!(
+                SupportedEncryptionVersions::<T>::get().contains(&version),
+                Error::<T>::UnsupportedEncryptionVersion
+            );
+
+            let cid_bounded = Self::bound_cid(cid.clone())?;
+            let disease_bounded: BoundedVec<u8, ConstU32<64>> = 
+                disease_id.clone().try_into().map_err(|_| Error::<T>::InvalidDiseaseId)?;
+            // An unset `DiseaseId` accepts any disease for backward compatibility
+            let tracked = DiseaseId::<T>::get();
+            ensure!(
+                tracked.is_empty() || tracked == disease_bounded,
+                Error::<T>::InvalidDiseaseId
+            );
+            ensure!(
+                !DiseaseUploadPaused::<T>::contains_key(&disease_bounded),
+                Error::<T>::DiseasePaused
+            );
+            let pk_id = CurrentPublicKey::<T>::get();
+
+            let health_status = EncryptedHealthStatus {
+                cid: cid_bounded,
+                disease_id: disease_bounded,
+                uploaded_at: Self::current_timestamp(),
+                encryption_version: version,
+                public_key_id: pk_id,
+                size_bytes,
+            };
+
+            let previous = EncryptedHealthStatuses::<T>::get(&who);
+            if previous.is_none() {
+                HealthReportedCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            }
+            let previous_size = previous.as_ref().map_or(0, |status| status.size_bytes);
+            TotalCiphertextBytes::<T>::mutate(|total| {
+                *total = total.saturating_sub(previous_size).saturating_add(size_bytes)
+            });
+            Self::retain_cid(&health_status.cid);
+            if let Some(previous) = previous.as_ref() {
+                Self::release_cid(&previous.cid);
+            }
+            EncryptedHealthStatuses::<T>::insert(&who, health_status);
+
+            // Replacements are always reported so the audit trail is complete
+            if let Some(previous) = previous {
+                Self::deposit_event(Event::HealthStatusReplaced {
+                    who,
+                    old_cid: previous.cid.into_inner(),
+                    new_cid: cid,
+                    disease_id,
+                });
+            } else if VerboseEvents::<T>::get() {
+                Self::deposit_event(Event::HealthStatusUploaded { 
+                    who, 
+                    cid, 
+                    disease_id,
+                    at: Self::current_timestamp(),
+                });
+            }
+            Ok(())
+        }
+
+        /// Purge health records older than the retention period.
+        ///
+        /// Inspects at most `MaxPurgePerBlock` records per call, resuming from
+        /// `RetentionCursor` so the whole map is covered over successive blocks.
+        fn purge_expired_health() -> Weight {
+            let db = T::DbWeight::get();
+            let Some(retention) = RetentionBlocks::<T>::get() else {
+                return db.reads(1);
+            };
+
+            let now = Self::current_timestamp();
+            let mut iter = match RetentionCursor::<T>::get() {
+                Some(account) => EncryptedHealthStatuses::<T>::iter_from(
+                    EncryptedHealthStatuses::<T>::hashed_key_for(account),
+                ),
+                None => EncryptedHealthStatuses::<T>::iter(),
+            };
+
+            let mut scanned: u64 = 0;
+            let mut last = None;
+            let mut expired = Vec::new();
+            for (who, status) in iter.by_ref().take(T::MaxPurgePerBlock::get() as usize) {
+                scanned += 1;
+                if now.saturating_sub(status.uploaded_at) > retention {
+                    expired.push((who.clone(), status));
+                }
+                last = Some(who);
+            }
+
+            match (iter.next().is_some(), last) {
+                (true, Some(last)) => RetentionCursor::<T>::put(last),
+                _ => RetentionCursor::<T>::kill(),
+            }
+
+            let purged = expired.len() as u64;
+            let mut freed: u64 = 0;
+            for (who, status) in expired {
+                EncryptedHealthStatuses::<T>::remove(&who);
+                HealthReportedCount::<T>::mutate(|count| *count = count.saturating_sub(1));
+                Self::release_cid(&status.cid);
+                freed = freed.saturating_add(status.size_bytes);
+                Self::deposit_event(Event::HealthStatusExpired { who });
+            }
+            TotalCiphertextBytes::<T>::mutate(|total| *total = total.saturating_sub(freed));
+
+            db.reads_writes(
+                scanned.saturating_add(purged).saturating_add(4),
+                purged.saturating_mul(3).saturating_add(2),
+            )
+        }
+
+        /// Number of contacts on a user's list, without decoding it
+        pub fn get_contact_degree(who: &T::AccountId) -> u32 {
+            ContactDegree::<T>::get(who)
+        }
+
+        /// Get all contacts for a user
+        pub fn get_user_contacts(who: &T::AccountId) -> Vec<T::AccountId> {
+            Contacts::<T>::get(who).into_iter().map(|record| record.contact).collect()
+        }
+
+        /// Get all contact records for a user, with contact times
+        pub fn get_contact_records(who: &T::AccountId) -> Vec<ContactRecord<T::AccountId>> {
+            Contacts::<T>::get(who).into_inner()
+        }
+
+        /// Accounts reachable from `user` within `depth` contact hops, nearest
+        /// first and without duplicates.
+        ///
+        /// `depth` is capped at `MAX_TRAVERSAL_DEPTH` and `max_nodes` at
+        /// `MAX_TRAVERSAL_NODES`; the search stops at whichever is hit first.
+        /// `user` itself is never included.
+        pub fn get_contacts_within(
+            user: &T::AccountId,
+            depth: u8,
+            max_nodes: u32,
+        ) -> Vec<T::AccountId> {
+            let max_nodes = max_nodes.min(MAX_TRAVERSAL_NODES) as usize;
+            let mut visited = BTreeSet::new();
+            visited.insert(user.clone());
+            let mut found = Vec::new();
+            let mut frontier = sp_std::vec![user.clone()];
+
+            for _ in 0..depth.min(MAX_TRAVERSAL_DEPTH) {
+                let mut next = Vec::new();
+                for who in frontier {
+                    for record in Contacts::<T>::get(&who) {
+                        if found.len() >= max_nodes {
+                            return found;
+                        }
+                        if visited.insert(record.contact.clone()) {
+                            found.push(record.contact.clone());
+                            next.push(record.contact);
+                        }
+                    }
+                }
+                if next.is_empty() {
+                    break;
+                }
+                frontier = next;
+            }
+            found
+        }
+
+        /// Contacts present in every listed user's contact list.
+        ///
+        /// No users yields no contacts and a single user yields their own list.
+        /// At most `MaxContacts` users are considered.
+        pub fn common_contacts(users: Vec<T::AccountId>) -> Vec<T::AccountId> {
+            let mut users = users.into_iter().take(T::MaxContacts::get() as usize);
+            let Some(first) = users.next() else {
+                return Vec::new();
+            };
+
+            let mut common = Self::get_user_contacts(&first);
+            for user in users {
+                if common.is_empty() {
+                    break;
+                }
+                let contacts = Contacts::<T>::get(&user);
+                common.retain(|contact| contacts.iter().any(|record| &record.contact == contact));
+            }
+            common
+        }
+
+        /// Check if user has uploaded encrypted health status
+        pub fn has_health_status(who: &T::AccountId) -> bool {
+            EncryptedHealthStatuses::<T>::contains_key(who)
+        }
+
+        /// Registered users who have no encrypted health status.
+        ///
+        /// Returns up to `limit` users after the `start` cursor plus the cursor
+        /// for the next page (`None` when done). A page may hold fewer than
+        /// `limit` users when `MAX_QUERY_SCAN` profiles were scanned first.
+        pub fn get_users_without_health_status(
+            limit: u32,
+            start: Option<T::AccountId>,
+        ) -> (Vec<T::AccountId>, Option<T::AccountId>) {
+            let mut last = start.clone();
+            let mut iter = match start {
+                Some(account) => {
+                    UserProfiles::<T>::iter_keys_from(UserProfiles::<T>::hashed_key_for(account))
+                },
+                None => UserProfiles::<T>::iter_keys(),
+            };
+
+            let mut users = Vec::new();
+            let mut scanned: u32 = 0;
+            while (users.len() as u32) < limit && scanned < MAX_QUERY_SCAN {
+                let Some(who) = iter.next() else {
+                    return (users, None);
+                };
+                scanned = scanned.saturating_add(1);
+                if !Self::has_health_status(&who) {
+                    users.push(who.clone());
+                }
+                last = Some(who);
+            }
+
+            let next = if iter.next().is_some() { last } else { None };
+            (users, next)
+        }
+
+        /// Timestamp at which a user acknowledged an exposure notification
+        pub fn has_acknowledged(who: &T::AccountId, exposure_ref: Vec<u8>) -> Option<u64> {
+            let ref_bounded: BoundedVec<u8, ConstU32<64>> = exposure_ref.try_into().ok()?;
+            ExposureAcks::<T>::get(who, ref_bounded)
+        }
+
+        /// Declared ciphertext size of a user's health upload
+        pub fn ciphertext_size(who: &T::AccountId) -> Option<u64> {
+            EncryptedHealthStatuses::<T>::get(who).map(|status| status.size_bytes)
+        }
+
+        /// Check if the chain holds anything about an account: a profile,
+        /// a non-empty contact list, a health status, an IVS score or an
+        /// exposure acknowledgement
+        pub fn has_any_data(who: &T::AccountId) -> bool {
+            UserProfiles::<T>::contains_key(who)
+                || !Contacts::<T>::get(who).is_empty()
+                || EncryptedHealthStatuses::<T>::contains_key(who)
+                || EncryptedIVSScores::<T>::contains_key(who)
+                || ExposureAcks::<T>::iter_key_prefix(who).next().is_some()
+        }
+
+        /// Distinct public key ids referenced by stored health records.
+        ///
+        /// Scans at most `scan_cap` records after the `start` cursor and returns
+        /// the keys seen plus the cursor for the next page (`None` when done).
+        pub fn keys_in_use(
+            scan_cap: u32,
+            start: Option<T::AccountId>,
+        ) -> (Vec<Vec<u8>>, Option<T::AccountId>) {
+            let mut iter = match start {
+                Some(account) => EncryptedHealthStatuses::<T>::iter_from(
+                    EncryptedHealthStatuses::<T>::hashed_key_for(account),
+                ),
+                None => EncryptedHealthStatuses::<T>::iter(),
+            };
+
+            let mut keys: Vec<Vec<u8>> = Vec::new();
+            let mut last = None;
+            for (who, status) in iter.by_ref().take(scan_cap as usize) {
+                let key = status.public_key_id.to_vec();
+                if !keys.contains(&key) {
+                    keys.push(key);
+                }
+                last = Some(who);
+            }
+
+            let next = if iter.next().is_some() { last } else { None };
+            (keys, next)
+        }
+
+        /// Users whose IVS predates their latest health upload (stale score).
+        ///
+        /// Returns up to `limit` stale users after the `start` cursor plus the
+        /// cursor for the next page (`None` when done). A page may hold fewer
+        /// than `limit` users when `MAX_QUERY_SCAN` IVS records were scanned first.
+        pub fn inconsistent_ivs_users(
+            limit: u32,
+            start: Option<T::AccountId>,
+        ) -> (Vec<T::AccountId>, Option<T::AccountId>) {
+            let mut last = start.clone();
+            let mut iter = match start {
+                Some(account) => EncryptedIVSScores::<T>::iter_from(
+                    EncryptedIVSScores::<T>::hashed_key_for(account),
+                ),
+                None => EncryptedIVSScores::<T>::iter(),
+            };
+
+            let mut users = Vec::new();
+            let mut scanned: u32 = 0;
+            while (users.len() as u32) < limit && scanned < MAX_QUERY_SCAN {
+                let Some((who, ivs)) = iter.next() else {
+                    return (users, None);
+                };
+                scanned = scanned.saturating_add(1);
+                let stale = EncryptedHealthStatuses::<T>::get(&who)
+                    .map_or(false, |health| ivs.computed_at < health.uploaded_at);
+                if stale {
+                    users.push(who.clone());
+                }
+                last = Some(who);
+            }
+
+            let next = if iter.next().is_some() { last } else { None };
+            (users, next)
+        }
+
+        /// Get a user's profile with non-public fields withheld
+        pub fn public_profile(who: &T::AccountId) -> Option<PublicProfile> {
+            let profile = UserProfiles::<T>::get(who)?;
+            let visibility = ProfileVisibilities::<T>::get(who);
+
+            Some(PublicProfile {
+                name: visibility.name_public.then_some(profile.name),
+                metadata: visibility.metadata_public.then_some(profile.metadata),
+                registered_at: profile.registered_at,
+                is_active: profile.is_active,
+            })
+        }
+
+        /// Profile, contact count, health status and IVS CID of a registered user
+        pub fn user_state(who: &T::AccountId) -> Option<UserState> {
+            let profile = UserProfiles::<T>::get(who)?;
+
+            Some(UserState {
+                profile,
+                contact_count: ContactDegree::<T>::get(who),
+                health_status: EncryptedHealthStatuses::<T>::get(who),
+                ivs_cid: EncryptedIVSScores::<T>::get(who).map(|ivs| ivs.cid),
+            })
+        }
+
+        /// Check whether a CID is referenced by the current public key, a health
+        /// status or an IVS record.
+        ///
+        /// Only the first `scan_cap` entries of each map are inspected, so `false`
+        /// means "not referenced within the scanned window" rather than a proof of
+        /// absence on large chains.
+        pub fn cid_in_use(cid: Vec<u8>, scan_cap: u32) -> bool {
+            if CurrentPublicKey::<T>::get().as_slice() == cid.as_slice() {
+                return true;
+            }
+
+            EncryptedHealthStatuses::<T>::iter_values()
+                .take(scan_cap as usize)
+                .any(|status| status.cid.as_slice() == cid.as_slice())
+                || EncryptedIVSScores::<T>::iter_values()
+                    .take(scan_cap as usize)
+                    .any(|ivs| ivs.cid.as_slice() == cid.as_slice())
+        }
+
+        /// Display metadata of the tracked disease, if set
+        pub fn get_disease_metadata() -> Option<DiseaseMetadata> {
+            DiseaseMetadataOf::<T>::get()
+        }
+
+        /// Get all registered users (for iteration)
+        pub fn get_all_users() -> Vec<T::AccountId> {
+            UserProfiles::<T>::iter_keys().collect()
+        }
+
+        /// Get registered users whose profile is active
+        pub fn get_active_users() -> Vec<T::AccountId> {
+            UserProfiles::<T>::iter()
+                .filter(|(_, profile)| profile.is_active)
+                .map(|(who, _)| who)
+                .collect()
+        }
+
+        /// Active users an all-users recompute should cover under
+        /// `RecomputeMissingPolicy`
+        pub fn eligible_users() -> Vec<T::AccountId> {
+            match RecomputeMissingPolicy::<T>::get() {
+                MissingDataPolicy::Skip => Self::get_active_users()
+                    .into_iter()
+                    .filter(|who| EncryptedHealthStatuses::<T>::contains_key(who))
+                    .collect(),
+                MissingDataPolicy::Fail => Self::get_active_users(),
+            }
+        }
+    }
+
+    impl<T: Config> ivs_primitives::ParticipationMetrics for Pallet<T> {
+        fn user_count() -> u32 {
+            UserCount::<T>::get()
         }
     }
 }
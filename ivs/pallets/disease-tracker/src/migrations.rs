@@ -0,0 +1,313 @@
+//! Storage migrations for the disease-tracker pallet.
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    storage_alias,
+    traits::UncheckedOnRuntimeUpgrade,
+};
+use sp_runtime::SaturatedConversion;
+use sp_std::vec::Vec;
+
+/// Records as laid out before any migration ran.
+mod v0 {
+    use super::*;
+
+    /// `EncryptedHealthStatus` before the declared ciphertext size was kept.
+    #[derive(Encode, Decode)]
+    pub struct EncryptedHealthStatus {
+        pub cid: BoundedVec<u8, ConstU32<128>>,
+        pub disease_id: BoundedVec<u8, ConstU32<64>>,
+        pub uploaded_at: u64,
+        pub encryption_version: u32,
+        pub public_key_id: BoundedVec<u8, ConstU32<64>>,
+    }
+}
+
+/// `Contacts` as laid out in storage versions 1 and 2, before contact weights.
+mod v2_contacts {
+    use super::*;
+
+    #[derive(Encode, Decode)]
+    pub struct ContactRecord<AccountId> {
+        pub contact: AccountId,
+        pub contacted_at: u64,
+        pub duration_blocks: u32,
+    }
+
+    #[storage_alias]
+    pub type Contacts<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<
+            ContactRecord<<T as frame_system::Config>::AccountId>,
+            <T as Config>::MaxContacts,
+        >,
+        ValueQuery,
+    >;
+}
+
+/// Migration from bare contact `AccountId`s to `ContactRecord`s.
+pub mod v1 {
+    use super::*;
+
+    /// Wraps every stored contact in a `ContactRecord` with `contacted_at = 0`
+    /// and `duration_blocks = 0`, since the original contact time is unknown,
+    /// and seeds `ContactDegree` from each list's length. Health records are
+    /// given `size_bytes = 0`, since their ciphertext size was never declared,
+    /// and `HealthReportedCount` is seeded with their number.
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            v2_contacts::Contacts::<T>::translate::<BoundedVec<T::AccountId, T::MaxContacts>, _>(
+                |who, old| {
+                    translated = translated.saturating_add(1);
+                    if !old.is_empty() {
+                        ContactDegree::<T>::insert(&who, old.len() as u32);
+                    }
+                    let records: Vec<_> = old
+                        .into_iter()
+                        .map(|contact| v2_contacts::ContactRecord {
+                            contact,
+                            contacted_at: 0,
+                            duration_blocks: 0,
+                        })
+                        .collect();
+                    // Same bound as before, so nothing is truncated
+                    Some(BoundedVec::truncate_from(records))
+                },
+            );
+
+            let mut statuses: u64 = 0;
+            EncryptedHealthStatuses::<T>::translate::<v0::EncryptedHealthStatus, _>(|_, old| {
+                statuses = statuses.saturating_add(1);
+                Some(EncryptedHealthStatus {
+                    cid: old.cid,
+                    disease_id: old.disease_id,
+                    uploaded_at: old.uploaded_at,
+                    encryption_version: old.encryption_version,
+                    public_key_id: old.public_key_id,
+                    size_bytes: 0,
+                })
+            });
+            HealthReportedCount::<T>::put(statuses.saturated_into::<u32>());
+
+            T::DbWeight::get().reads_writes(
+                translated.saturating_add(statuses),
+                translated.saturating_mul(2).saturating_add(statuses).saturating_add(1),
+            )
+        }
+    }
+
+    /// [`InnerMigrateV0ToV1`] gated on the on-chain storage version being 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Import of records kept by the legacy `ivs` pallet.
+pub mod v2 {
+    use super::*;
+
+    #[storage_alias(dynamic)]
+    type Users<P: Get<&'static str>, T: Config> =
+        StorageMap<P, Blake2_128Concat, <T as frame_system::Config>::AccountId, ()>;
+
+    #[storage_alias(dynamic)]
+    type LegacyContacts<P: Get<&'static str>, T: Config> = StorageMap<
+        P,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<<T as frame_system::Config>::AccountId, ConstU32<200>>,
+        ValueQuery,
+    >;
+
+    #[storage_alias(dynamic)]
+    type HealthCid<P: Get<&'static str>, T: Config> = StorageMap<
+        P,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<u8, ConstU32<128>>,
+    >;
+
+    #[storage_alias(dynamic)]
+    type IvsCid<P: Get<&'static str>, T: Config> = StorageMap<
+        P,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<u8, ConstU32<128>>,
+    >;
+
+    /// Copies every user of the legacy `ivs` pallet, stored under the
+    /// `LegacyPallet` prefix, into disease-tracker storage.
+    ///
+    /// Profiles get empty name and metadata; contacts beyond `MaxContacts` are
+    /// dropped; health and IVS CIDs are wrapped with `encryption_version = 1`,
+    /// the parachain's `DiseaseId` and unknown (`0`) timestamps. Accounts that
+    /// already have a disease-tracker record of a given kind keep it. The legacy
+    /// storage is left in place.
+    pub struct InnerMigrateV1ToV2<T, LegacyPallet>(core::marker::PhantomData<(T, LegacyPallet)>);
+
+    impl<T: Config, LegacyPallet: Get<&'static str>> UncheckedOnRuntimeUpgrade
+        for InnerMigrateV1ToV2<T, LegacyPallet>
+    {
+        fn on_runtime_upgrade() -> Weight {
+            let mut reads: u64 = 0;
+            let mut writes: u64 = 0;
+            let disease_id = DiseaseId::<T>::get();
+
+            for who in Users::<LegacyPallet, T>::iter_keys() {
+                reads = reads.saturating_add(5);
+
+                if !UserProfiles::<T>::contains_key(&who) {
+                    let profile = UserProfile {
+                        name: Default::default(),
+                        metadata: Default::default(),
+                        registered_at: 0,
+                        is_active: true,
+                    };
+                    UserProfiles::<T>::insert(&who, profile);
+                    UserCount::<T>::mutate(|count| *count = count.saturating_add(1));
+                    writes = writes.saturating_add(2);
+                }
+
+                let legacy_contacts = LegacyContacts::<LegacyPallet, T>::get(&who);
+                if !legacy_contacts.is_empty() && !v2_contacts::Contacts::<T>::contains_key(&who) {
+                    let records: Vec<_> = legacy_contacts
+                        .into_iter()
+                        .map(|contact| v2_contacts::ContactRecord {
+                            contact,
+                            contacted_at: 0,
+                            duration_blocks: 0,
+                        })
+                        .collect();
+                    let records = BoundedVec::<_, T::MaxContacts>::truncate_from(records);
+                    ContactDegree::<T>::insert(&who, records.len() as u32);
+                    v2_contacts::Contacts::<T>::insert(&who, records);
+                    writes = writes.saturating_add(2);
+                }
+
+                if let Some(cid) = HealthCid::<LegacyPallet, T>::get(&who) {
+                    if !EncryptedHealthStatuses::<T>::contains_key(&who) {
+                        let status = EncryptedHealthStatus {
+                            cid,
+                            disease_id: disease_id.clone(),
+                            uploaded_at: 0,
+                            encryption_version: 1,
+                            public_key_id: Default::default(),
+                            size_bytes: 0,
+                        };
+                        EncryptedHealthStatuses::<T>::insert(&who, status);
+                        HealthReportedCount::<T>::mutate(|count| *count = count.saturating_add(1));
+                        writes = writes.saturating_add(2);
+                    }
+                }
+
+                if let Some(cid) = IvsCid::<LegacyPallet, T>::get(&who) {
+                    if !EncryptedIVSScores::<T>::contains_key(&who) {
+                        let ivs = EncryptedIVS {
+                            cid,
+                            computed_at: 0,
+                            parameters: Default::default(),
+                        };
+                        EncryptedIVSScores::<T>::insert(&who, ivs);
+                        writes = writes.saturating_add(1);
+                    }
+                }
+            }
+
+            T::DbWeight::get().reads_writes(reads.saturating_add(1), writes)
+        }
+    }
+
+    /// [`InnerMigrateV1ToV2`] gated on the on-chain storage version being 1.
+    pub type MigrateV1ToV2<T, LegacyPallet> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T, LegacyPallet>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration adding a weight to every contact edge.
+pub mod v3 {
+    use super::*;
+
+    /// Gives every stored contact `weight = DEFAULT_CONTACT_WEIGHT`.
+    pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            Contacts::<T>::translate::<
+                BoundedVec<v2_contacts::ContactRecord<T::AccountId>, T::MaxContacts>,
+                _,
+            >(|_, old| {
+                translated = translated.saturating_add(1);
+                let records: Vec<_> = old
+                    .into_iter()
+                    .map(|record| ContactRecord {
+                        contact: record.contact,
+                        contacted_at: record.contacted_at,
+                        duration_blocks: record.duration_blocks,
+                        weight: DEFAULT_CONTACT_WEIGHT,
+                    })
+                    .collect();
+                // Same bound as before, so nothing is truncated
+                Some(BoundedVec::truncate_from(records))
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV2ToV3`] gated on the on-chain storage version being 2.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration seeding CID reference counts.
+pub mod v4 {
+    use super::*;
+
+    /// Counts one `CidRefCount` reference per stored health status and IVS
+    /// record.
+    pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut counted: u64 = 0;
+            let health = EncryptedHealthStatuses::<T>::iter_values().map(|status| status.cid);
+            let ivs = EncryptedIVSScores::<T>::iter_values().map(|ivs| ivs.cid);
+            for cid in health.chain(ivs) {
+                counted = counted.saturating_add(1);
+                CidRefCount::<T>::mutate(cid, |count| *count = count.saturating_add(1));
+            }
+
+            T::DbWeight::get().reads_writes(counted.saturating_mul(2), counted)
+        }
+    }
+
+    /// [`InnerMigrateV3ToV4`] gated on the on-chain storage version being 3.
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,62 @@
+//! Mock runtime for pallet-disease-tracker tests
+
+use crate as pallet_disease_tracker;
+use core::cell::Cell;
+use frame_support::{
+    derive_impl,
+    traits::{ConstU32, ConstU64, Get},
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        DiseaseTracker: pallet_disease_tracker,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+thread_local! {
+    /// Whether `MockCidPrefixes` also accepts base58btc CIDv1s (`z`)
+    pub static EXTENDED_CID_PREFIXES: Cell<bool> = const { Cell::new(false) };
+}
+
+/// `DefaultCidPrefixes`, extended while `EXTENDED_CID_PREFIXES` is set
+pub struct MockCidPrefixes;
+impl Get<&'static [&'static [u8]]> for MockCidPrefixes {
+    fn get() -> &'static [&'static [u8]] {
+        const EXTENDED: &[&[u8]] = &[b"Qm", b"bafy", b"bafk", b"z"];
+        if EXTENDED_CID_PREFIXES.with(Cell::get) {
+            EXTENDED
+        } else {
+            ivs_primitives::DefaultCidPrefixes::get()
+        }
+    }
+}
+
+impl pallet_disease_tracker::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type MaxContacts = ConstU32<10>;
+    type ComputeOrigin = pallet_disease_tracker::DefaultComputeOrigin<Test>;
+    type AllowedCidPrefixes = MockCidPrefixes;
+    type MaxPurgePerBlock = ConstU32<2>;
+    type MaxInvariantScan = ConstU32<20>;
+    type MaxCiphertextBytes = ConstU64<1_000>;
+}
+
+/// Externalities with an empty genesis, starting at block 1 so events are kept
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
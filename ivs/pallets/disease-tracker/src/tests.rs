@@ -0,0 +1,668 @@
+//! Unit tests for pallet-disease-tracker
+
+use crate::{
+    mock::*, ContactRecord, Contacts, Error, Event, InvariantCode, MissingDataPolicy,
+    ProfileVisibility, UserCount, UserProfiles, DEFAULT_CONTACT_WEIGHT,
+};
+use frame_support::{assert_noop, assert_ok, traits::Hooks, BoundedVec};
+use ivs_primitives::ParticipationMetrics;
+use sp_runtime::DispatchError;
+
+fn register(who: u64) {
+    assert_ok!(DiseaseTracker::register_user(
+        RuntimeOrigin::signed(who),
+        b"user".to_vec(),
+        Vec::new()
+    ));
+}
+
+/// A well-formed base32 CIDv1 accepted by the default `AllowedCidPrefixes`
+fn cid(tag: &[u8]) -> Vec<u8> {
+    let mut cid = b"bafy".to_vec();
+    cid.extend_from_slice(tag);
+    cid
+}
+
+/// Upload a version 1 health record declaring `size_bytes`
+fn upload(who: u64, tag: &[u8], size_bytes: u64) {
+    assert_ok!(DiseaseTracker::upload_encrypted_health(
+        RuntimeOrigin::signed(who),
+        cid(tag),
+        b"covid".to_vec(),
+        1,
+        size_bytes
+    ));
+}
+
+fn add_contact(who: u64, contact: u64) {
+    assert_ok!(DiseaseTracker::add_contact(
+        RuntimeOrigin::signed(who),
+        contact
+    ));
+}
+
+#[test]
+fn has_any_data_covers_contact_only_accounts() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        assert!(DiseaseTracker::has_any_data(&1));
+
+        // A contact list left behind without a profile still counts
+        let record = ContactRecord {
+            contact: 3,
+            contacted_at: 1,
+            duration_blocks: 0,
+            weight: DEFAULT_CONTACT_WEIGHT,
+        };
+        Contacts::<Test>::insert(2, BoundedVec::truncate_from(vec![record]));
+        assert!(DiseaseTracker::has_any_data(&2));
+
+        assert!(!DiseaseTracker::has_any_data(&3));
+    });
+}
+
+#[test]
+fn verbose_events_toggle_suppresses_uploads() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+
+        assert_ok!(DiseaseTracker::set_verbose_events(
+            RuntimeOrigin::root(),
+            false
+        ));
+        upload(1, b"one", 10);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::DiseaseTracker(Event::HealthStatusUploaded { .. })
+        )));
+
+        assert_ok!(DiseaseTracker::set_verbose_events(
+            RuntimeOrigin::root(),
+            true
+        ));
+        upload(2, b"two", 10);
+        System::assert_last_event(
+            Event::HealthStatusUploaded {
+                who: 2,
+                cid: cid(b"two"),
+                disease_id: b"covid".to_vec(),
+                at: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn keys_in_use_lists_each_key_once() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            register(who);
+        }
+        assert_ok!(DiseaseTracker::update_public_key(
+            RuntimeOrigin::root(),
+            b"key-a".to_vec()
+        ));
+        upload(1, b"one", 10);
+        assert_ok!(DiseaseTracker::update_public_key(
+            RuntimeOrigin::root(),
+            b"key-b".to_vec()
+        ));
+        upload(2, b"two", 10);
+        upload(3, b"three", 10);
+
+        let (mut keys, next) = DiseaseTracker::keys_in_use(10, None);
+        keys.sort();
+        assert_eq!(keys, vec![b"key-a".to_vec(), b"key-b".to_vec()]);
+        assert_eq!(next, None);
+    });
+}
+
+#[test]
+fn users_without_health_status_are_paged_by_result_count() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=4 {
+            register(who);
+        }
+        upload(4, b"four", 10);
+
+        let (mut missing, next) = DiseaseTracker::get_users_without_health_status(10, None);
+        missing.sort();
+        assert_eq!(missing, vec![1, 2, 3]);
+        assert_eq!(next, None);
+
+        // `limit` caps the users returned, not the profiles scanned
+        let (first, next) = DiseaseTracker::get_users_without_health_status(2, None);
+        assert_eq!(first.len(), 2);
+        assert!(next.is_some());
+        let (second, last) = DiseaseTracker::get_users_without_health_status(2, next);
+        assert_eq!(second.len(), 1);
+        assert_eq!(last, None);
+
+        let mut all = [first, second].concat();
+        all.sort();
+        assert_eq!(all, vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn inconsistent_ivs_users_finds_scores_older_than_health() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=4 {
+            register(who);
+        }
+        upload(4, b"four", 10);
+        for who in 1..=4 {
+            assert_ok!(DiseaseTracker::store_encrypted_ivs(
+                RuntimeOrigin::root(),
+                who,
+                cid(b"ivs"),
+                Vec::new()
+            ));
+        }
+
+        System::set_block_number(2);
+        for who in 1..=3 {
+            upload(who, b"new", 10);
+        }
+
+        let (mut stale, next) = DiseaseTracker::inconsistent_ivs_users(10, None);
+        stale.sort();
+        assert_eq!(stale, vec![1, 2, 3]);
+        assert_eq!(next, None);
+
+        // `limit` caps the users returned, not the records scanned
+        let (first, next) = DiseaseTracker::inconsistent_ivs_users(2, None);
+        assert_eq!(first.len(), 2);
+        assert!(next.is_some());
+        let (second, last) = DiseaseTracker::inconsistent_ivs_users(2, next);
+        assert_eq!(second.len(), 1);
+        assert_eq!(last, None);
+
+        let mut all = [first, second].concat();
+        all.sort();
+        assert_eq!(all, vec![1, 2, 3]);
+    });
+}
+
+#[test]
+fn public_profile_withholds_private_metadata() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(DiseaseTracker::register_user(
+            RuntimeOrigin::signed(1),
+            b"alice".to_vec(),
+            b"{\"age\":40}".to_vec()
+        ));
+        assert_eq!(DiseaseTracker::public_profile(&1).unwrap().name, None);
+
+        assert_ok!(DiseaseTracker::set_visibility(
+            RuntimeOrigin::signed(1),
+            ProfileVisibility {
+                name_public: true,
+                metadata_public: false
+            }
+        ));
+        let profile = DiseaseTracker::public_profile(&1).unwrap();
+        assert_eq!(profile.name.unwrap().to_vec(), b"alice".to_vec());
+        assert_eq!(profile.metadata, None);
+    });
+}
+
+#[test]
+fn aggregator_para_id_is_root_only() {
+    new_test_ext().execute_with(|| {
+        assert_noop!(
+            DiseaseTracker::set_aggregator(RuntimeOrigin::signed(1), 2000),
+            DispatchError::BadOrigin
+        );
+
+        assert_ok!(DiseaseTracker::set_aggregator(RuntimeOrigin::root(), 2000));
+        assert_eq!(DiseaseTracker::aggregator_para_id(), Some(2000));
+    });
+}
+
+#[test]
+fn upload_and_request_ivs_rolls_back_on_bad_cid() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        let events = System::events().len();
+
+        assert_noop!(
+            DiseaseTracker::upload_and_request_ivs(
+                RuntimeOrigin::signed(1),
+                b"not-a-cid".to_vec(),
+                b"covid".to_vec(),
+                1,
+                10
+            ),
+            Error::<Test>::InvalidCID
+        );
+        assert!(!DiseaseTracker::has_health_status(&1));
+        assert_eq!(System::events().len(), events);
+
+        assert_ok!(DiseaseTracker::upload_and_request_ivs(
+            RuntimeOrigin::signed(1),
+            cid(b"one"),
+            b"covid".to_vec(),
+            1,
+            10
+        ));
+        assert!(DiseaseTracker::has_health_status(&1));
+        System::assert_last_event(
+            Event::IvsComputationRequested {
+                who: 1,
+                disease_id: b"covid".to_vec(),
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn cid_in_use_finds_referenced_cids() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        upload(1, b"one", 10);
+
+        assert!(DiseaseTracker::cid_in_use(cid(b"one"), 10));
+        assert!(!DiseaseTracker::cid_in_use(cid(b"two"), 10));
+    });
+}
+
+#[test]
+fn cid_prefixes_follow_the_configured_allow_list() {
+    new_test_ext().execute_with(|| {
+        let base58 = b"zdj7WWeQ43G6JJvLWQWZpyHuAMq6uYWRjkBXFad11vE2LHhQ7".to_vec();
+        assert!(!DiseaseTracker::validate_cid(&base58));
+
+        EXTENDED_CID_PREFIXES.with(|extended| extended.set(true));
+        assert!(DiseaseTracker::validate_cid(&base58));
+        assert!(DiseaseTracker::validate_cid(&cid(b"one")));
+        // An allowed prefix still needs a body in that multibase's alphabet
+        assert!(!DiseaseTracker::validate_cid(b"zdj7W0lO"));
+        EXTENDED_CID_PREFIXES.with(|extended| extended.set(false));
+    });
+}
+
+#[test]
+fn retention_purges_only_expired_health_records() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+        assert_ok!(DiseaseTracker::set_retention_period(
+            RuntimeOrigin::root(),
+            Some(5)
+        ));
+        upload(1, b"old", 10);
+        System::set_block_number(5);
+        upload(2, b"recent", 20);
+
+        System::set_block_number(7);
+        DiseaseTracker::on_initialize(7);
+
+        assert!(!DiseaseTracker::has_health_status(&1));
+        assert!(DiseaseTracker::has_health_status(&2));
+        assert_eq!(DiseaseTracker::health_reported_count(), 1);
+        assert_eq!(DiseaseTracker::total_ciphertext_bytes(), 20);
+        System::assert_has_event(Event::HealthStatusExpired { who: 1 }.into());
+    });
+}
+
+#[test]
+fn migrate_account_re_points_every_edge() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            register(who);
+        }
+        add_contact(1, 2);
+        add_contact(1, 4);
+        add_contact(2, 1);
+        add_contact(2, 4);
+        add_contact(3, 1);
+        assert_ok!(DiseaseTracker::acknowledge_exposure(
+            RuntimeOrigin::signed(1),
+            b"exposure".to_vec()
+        ));
+
+        assert_ok!(DiseaseTracker::migrate_account(RuntimeOrigin::signed(1), 4));
+        assert!(DiseaseTracker::user_profile(1).is_none());
+        assert!(DiseaseTracker::user_profile(4).is_some());
+        assert_eq!(
+            DiseaseTracker::has_acknowledged(&4, b"exposure".to_vec()),
+            Some(1)
+        );
+        assert_eq!(
+            DiseaseTracker::has_acknowledged(&1, b"exposure".to_vec()),
+            None
+        );
+
+        // The edge to the new account itself is dropped
+        assert_eq!(DiseaseTracker::get_user_contacts(&4), vec![2]);
+        // A list already holding the new account is not given a duplicate
+        assert_eq!(DiseaseTracker::get_user_contacts(&2), vec![4]);
+        assert_eq!(DiseaseTracker::get_contact_degree(&2), 1);
+        // Lists the old account never appeared in wait for the batch
+        assert_eq!(DiseaseTracker::get_user_contacts(&3), vec![1]);
+        assert_eq!(
+            DiseaseTracker::pending_contact_migration(1),
+            Some((4, None))
+        );
+        assert_noop!(
+            DiseaseTracker::register_user(RuntimeOrigin::signed(1), b"user".to_vec(), Vec::new()),
+            Error::<Test>::MigrationPending
+        );
+
+        for _ in 0..10 {
+            if DiseaseTracker::pending_contact_migration(1).is_none() {
+                break;
+            }
+            assert_ok!(DiseaseTracker::migrate_account_contacts_batch(
+                RuntimeOrigin::signed(9),
+                1
+            ));
+        }
+        assert_eq!(DiseaseTracker::pending_contact_migration(1), None);
+        assert_eq!(DiseaseTracker::get_user_contacts(&3), vec![4]);
+        assert!(!DiseaseTracker::has_any_data(&1));
+        register(1);
+    });
+}
+
+#[test]
+fn verify_invariants_reports_counter_drift() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        assert_ok!(DiseaseTracker::verify_invariants(RuntimeOrigin::root()));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::DiseaseTracker(Event::InvariantViolation { .. })
+        )));
+
+        UserCount::<Test>::put(3);
+        assert_ok!(DiseaseTracker::verify_invariants(RuntimeOrigin::root()));
+        System::assert_last_event(
+            Event::InvariantViolation {
+                code: InvariantCode::UserCountMismatch,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn paused_disease_rejects_uploads() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        assert_ok!(DiseaseTracker::pause_disease_uploads(
+            RuntimeOrigin::root(),
+            b"covid".to_vec()
+        ));
+        assert_noop!(
+            DiseaseTracker::upload_encrypted_health(
+                RuntimeOrigin::signed(1),
+                cid(b"one"),
+                b"covid".to_vec(),
+                1,
+                10
+            ),
+            Error::<Test>::DiseasePaused
+        );
+
+        assert_ok!(DiseaseTracker::unpause_disease_uploads(
+            RuntimeOrigin::root(),
+            b"covid".to_vec()
+        ));
+        upload(1, b"one", 10);
+        assert!(DiseaseTracker::has_health_status(&1));
+    });
+}
+
+#[test]
+fn common_contacts_returns_shared_contact_only() {
+    new_test_ext().execute_with(|| {
+        for (who, contacts) in [(1, vec![4, 5]), (2, vec![6, 4]), (3, vec![5, 6, 4])] {
+            register(who);
+            for contact in contacts {
+                add_contact(who, contact);
+            }
+        }
+
+        assert_eq!(DiseaseTracker::common_contacts(vec![1, 2, 3]), vec![4]);
+    });
+}
+
+#[test]
+fn compact_contacts_prunes_unregistered_accounts() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+        add_contact(1, 2);
+        add_contact(1, 3);
+
+        assert_ok!(DiseaseTracker::compact_contacts(RuntimeOrigin::root(), 1));
+        assert_eq!(DiseaseTracker::get_user_contacts(&1), vec![2]);
+        assert_eq!(DiseaseTracker::get_contact_degree(&1), 1);
+        System::assert_last_event(
+            Event::ContactsCompacted {
+                user: 1,
+                removed: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn exposure_acknowledgement_records_timestamp() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        System::set_block_number(4);
+
+        assert_ok!(DiseaseTracker::acknowledge_exposure(
+            RuntimeOrigin::signed(1),
+            b"exposure".to_vec()
+        ));
+        assert_eq!(
+            DiseaseTracker::has_acknowledged(&1, b"exposure".to_vec()),
+            Some(4)
+        );
+        assert_eq!(
+            DiseaseTracker::has_acknowledged(&1, b"other".to_vec()),
+            None
+        );
+        System::assert_last_event(
+            Event::ExposureAcknowledged {
+                who: 1,
+                exposure_ref: b"exposure".to_vec(),
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            DiseaseTracker::acknowledge_exposure(RuntimeOrigin::signed(1), vec![b'x'; 65]),
+            Error::<Test>::InvalidExposureRef
+        );
+    });
+}
+
+#[test]
+fn participation_metrics_match_storage() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+
+        assert_eq!(<DiseaseTracker as ParticipationMetrics>::user_count(), 2);
+        assert_eq!(UserProfiles::<Test>::iter().count(), 2);
+        assert_eq!(
+            <DiseaseTracker as ParticipationMetrics>::committee_size(),
+            0
+        );
+    });
+}
+
+#[test]
+fn missing_data_policy_selects_eligible_users() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+        upload(1, b"one", 10);
+
+        assert_eq!(DiseaseTracker::eligible_users(), vec![1]);
+
+        assert_ok!(DiseaseTracker::set_recompute_missing_policy(
+            RuntimeOrigin::root(),
+            MissingDataPolicy::Fail
+        ));
+        let mut users = DiseaseTracker::eligible_users();
+        users.sort();
+        assert_eq!(users, vec![1, 2]);
+    });
+}
+
+#[test]
+fn declared_ciphertext_size_is_capped() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        upload(1, b"one", 1_000);
+        assert_eq!(DiseaseTracker::ciphertext_size(&1), Some(1_000));
+
+        assert_noop!(
+            DiseaseTracker::upload_encrypted_health(
+                RuntimeOrigin::signed(1),
+                cid(b"two"),
+                b"covid".to_vec(),
+                1,
+                1_001
+            ),
+            Error::<Test>::CiphertextTooLarge
+        );
+    });
+}
+
+#[test]
+fn ciphertext_footprint_tracks_uploads_and_erasure() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+        upload(1, b"one", 300);
+        upload(2, b"two", 500);
+        assert_eq!(DiseaseTracker::total_ciphertext_bytes(), 800);
+
+        // A re-upload replaces the previous size
+        upload(2, b"three", 400);
+        assert_eq!(DiseaseTracker::total_ciphertext_bytes(), 700);
+
+        assert_ok!(DiseaseTracker::purge_user(RuntimeOrigin::root(), 1));
+        assert_eq!(DiseaseTracker::total_ciphertext_bytes(), 400);
+    });
+}
+
+#[test]
+fn cid_refcount_announces_unreferenced_cids() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+        upload(1, b"shared", 10);
+        upload(2, b"shared", 10);
+        assert_eq!(DiseaseTracker::get_cid_refcount(cid(b"shared")), 2);
+
+        // One reference left: nothing to unpin yet
+        upload(1, b"one", 10);
+        assert_eq!(DiseaseTracker::get_cid_refcount(cid(b"shared")), 1);
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::DiseaseTracker(Event::CidUnreferenced { .. })
+        )));
+
+        upload(2, b"two", 10);
+        assert_eq!(DiseaseTracker::get_cid_refcount(cid(b"shared")), 0);
+        System::assert_has_event(
+            Event::CidUnreferenced {
+                cid: cid(b"shared"),
+            }
+            .into(),
+        );
+
+        assert_ok!(DiseaseTracker::purge_user(RuntimeOrigin::root(), 1));
+        assert_eq!(DiseaseTracker::get_cid_refcount(cid(b"one")), 0);
+        System::assert_has_event(Event::CidUnreferenced { cid: cid(b"one") }.into());
+    });
+}
+
+#[test]
+fn attest_no_data_rejects_known_accounts() {
+    new_test_ext().execute_with(|| {
+        register(1);
+
+        assert_ok!(DiseaseTracker::attest_no_data(RuntimeOrigin::root(), 2));
+        System::assert_last_event(Event::NoDataAttested { subject: 2, at: 1 }.into());
+
+        assert_noop!(
+            DiseaseTracker::attest_no_data(RuntimeOrigin::root(), 1),
+            Error::<Test>::DataExists
+        );
+    });
+}
+
+#[test]
+fn purge_user_erases_records_acks_and_inbound_edges() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            register(who);
+        }
+        add_contact(1, 2);
+        add_contact(2, 1);
+        add_contact(3, 1);
+        upload(1, b"one", 10);
+        for exposure_ref in [b"one", b"two", b"six"] {
+            assert_ok!(DiseaseTracker::acknowledge_exposure(
+                RuntimeOrigin::signed(1),
+                exposure_ref.to_vec()
+            ));
+        }
+
+        assert_ok!(DiseaseTracker::purge_user(RuntimeOrigin::root(), 1));
+        assert!(DiseaseTracker::user_profile(1).is_none());
+        assert!(!DiseaseTracker::has_health_status(&1));
+        assert_eq!(DiseaseTracker::user_count(), 2);
+        assert!(DiseaseTracker::get_user_contacts(&2).is_empty());
+        // One acknowledgement is left for the batch, so the account still has data
+        assert_noop!(
+            DiseaseTracker::attest_no_data(RuntimeOrigin::root(), 1),
+            Error::<Test>::DataExists
+        );
+
+        for _ in 0..10 {
+            if DiseaseTracker::pending_contact_purge(1).is_none() {
+                break;
+            }
+            assert_ok!(DiseaseTracker::purge_user_contacts_batch(
+                RuntimeOrigin::root(),
+                1
+            ));
+        }
+        assert_eq!(DiseaseTracker::pending_contact_purge(1), None);
+        assert!(DiseaseTracker::get_user_contacts(&3).is_empty());
+        assert!(!DiseaseTracker::has_any_data(&1));
+        assert_ok!(DiseaseTracker::attest_no_data(RuntimeOrigin::root(), 1));
+    });
+}
+
+#[test]
+fn self_contacts_are_rejected() {
+    new_test_ext().execute_with(|| {
+        register(1);
+        register(2);
+
+        assert_noop!(
+            DiseaseTracker::add_contact(RuntimeOrigin::signed(1), 1),
+            Error::<Test>::SelfContactNotAllowed
+        );
+        assert_noop!(
+            DiseaseTracker::add_contacts_batch(RuntimeOrigin::signed(1), vec![2, 1]),
+            Error::<Test>::SelfContactNotAllowed
+        );
+        assert!(DiseaseTracker::get_user_contacts(&1).is_empty());
+    });
+}
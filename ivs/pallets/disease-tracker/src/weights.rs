@@ -0,0 +1,190 @@
+//! Weights for `pallet_disease_tracker`
+//!
+//! Hand-written conservative estimates, not benchmark output: no runtime
+//! includes this pallet yet, so it has not been benchmarked.
+//!
+//! Each weight is a rounded-up execution allowance plus the storage reads and
+//! writes of the call's worst-case path, as listed on each `WeightInfo` method.
+//! Calls not listed here still use a flat weight.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_disease_tracker`.
+pub trait WeightInfo {
+	/// `c`: contacts on the user's list, `a`: exposure acknowledgements removed
+	/// - `UserProfiles`: 1 read, 1 write
+	/// - `UserCount`: 1 read, 1 write
+	/// - `ProfileVisibilities`: 1 write
+	/// - `EncryptedHealthStatuses`: 1 read, 1 write
+	/// - `HealthReportedCount`: 1 read, 1 write
+	/// - `TotalCiphertextBytes`: 1 read, 1 write
+	/// - `CidRefCount`: 2 reads, 2 writes
+	/// - `EncryptedIVSScores`: 1 read, 1 write
+	/// - `ContactDegree`: `c + 1` writes
+	/// - `Contacts`: `c + 1` reads, `c + 1` writes
+	/// - `ExposureAcks`: `a + 1` reads, `a` writes
+	/// - `PendingContactPurges`: 1 write
+	fn purge_user(c: u32, a: u32, ) -> Weight;
+	/// `s`: contact lists scanned, `a`: exposure acknowledgements removed
+	/// - `PendingContactPurges`: 1 read, 1 write
+	/// - `ExposureAcks`: `a + 1` reads, `a` writes
+	/// - `Contacts`: `s + 1` reads, `s` writes
+	/// - `ContactDegree`: `s` writes
+	fn purge_user_contacts_batch(s: u32, a: u32, ) -> Weight;
+	/// `c`: contacts on the old account's list, `a`: exposure acknowledgements moved
+	/// - `UserProfiles`: 2 reads, 2 writes
+	/// - `ContactMigrationTargets`: 1 read, 1 write
+	/// - `PendingContactMigrations`: 1 read, 1 write
+	/// - `ProfileVisibilities`: 1 read, 2 writes
+	/// - `EncryptedHealthStatuses`: 2 reads, 2 writes
+	/// - `EncryptedIVSScores`: 2 reads, 2 writes
+	/// - `Contacts`: `c + 2` reads, `c + 2` writes
+	/// - `ContactDegree`: `c + 2` writes
+	/// - `ExposureAcks`: `a + 2` reads, `2a` writes
+	fn migrate_account(c: u32, a: u32, ) -> Weight;
+	/// `s`: contact lists scanned, `a`: exposure acknowledgements moved
+	/// - `PendingContactMigrations`: 1 read, 1 write
+	/// - `UserProfiles`: 1 read
+	/// - `ExposureAcks`: `a + 1` reads, `2a` writes
+	/// - `Contacts`: `s + 1` reads, `s` writes
+	/// - `ContactDegree`: `s` writes
+	/// - `ContactMigrationTargets`: 1 write
+	fn migrate_account_contacts_batch(s: u32, a: u32, ) -> Weight;
+	/// `n`: accounts passed
+	/// - `UserProfiles`: 1 read
+	/// - `ContactCap`: 1 read
+	/// - `Contacts`: 1 read, 1 write
+	/// - `ContactDegree`: 1 write
+	fn add_contacts_batch(n: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_disease_tracker` priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn purge_user(c: u32, a: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(12_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn purge_user_contacts_batch(s: u32, a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn migrate_account(c: u32, a: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(13_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn migrate_account_contacts_batch(s: u32, a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn add_contacts_batch(n: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 12515)
+			.saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn purge_user(c: u32, a: u32, ) -> Weight {
+		Weight::from_parts(60_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(12_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn purge_user_contacts_batch(s: u32, a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn migrate_account(c: u32, a: u32, ) -> Weight {
+		Weight::from_parts(70_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(13_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(c.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(c.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn migrate_account_contacts_batch(s: u32, a: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 12515)
+			.saturating_add(Weight::from_parts(12_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(8_000_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(s.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(a.into())))
+			.saturating_add(Weight::from_parts(0, 11525).saturating_mul(s.into()))
+			.saturating_add(Weight::from_parts(0, 2612).saturating_mul(a.into()))
+	}
+	fn add_contacts_batch(n: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 12515)
+			.saturating_add(Weight::from_parts(1_200_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+}
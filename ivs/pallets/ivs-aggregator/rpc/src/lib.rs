@@ -0,0 +1,76 @@
+//! # IVS Aggregator RPC
+//!
+//! JSON-RPC methods over the aggregator runtime API for off-chain clients.
+
+use std::sync::Arc;
+
+use codec::Codec;
+use jsonrpsee::{
+    core::RpcResult,
+    proc_macros::rpc,
+    types::error::{ErrorObject, ErrorObjectOwned},
+};
+use serde::de::DeserializeOwned;
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_runtime::traits::Block as BlockT;
+
+pub use pallet_ivs_aggregator_runtime_api::IvsAggregatorApi as IvsAggregatorRuntimeApi;
+
+#[rpc(client, server)]
+pub trait IvsAggregatorApi<BlockHash, AccountId> {
+    /// Whether `requester` may decrypt `user`'s data under the policy in
+    /// force at the given block
+    #[method(name = "ivsAggregator_canDecrypt")]
+    fn can_decrypt(
+        &self,
+        requester: AccountId,
+        user: AccountId,
+        at: Option<BlockHash>,
+    ) -> RpcResult<bool>;
+}
+
+/// Serves the aggregator RPC methods from a client's runtime API
+pub struct IvsAggregator<C, B> {
+    client: Arc<C>,
+    _marker: std::marker::PhantomData<B>,
+}
+
+impl<C, B> IvsAggregator<C, B> {
+    pub fn new(client: Arc<C>) -> Self {
+        Self {
+            client,
+            _marker: Default::default(),
+        }
+    }
+}
+
+/// Error code returned when the runtime call fails
+const RUNTIME_ERROR: i32 = 1;
+
+fn runtime_error(desc: &'static str, error: impl ToString) -> ErrorObjectOwned {
+    ErrorObject::owned(RUNTIME_ERROR, desc, Some(error.to_string()))
+}
+
+impl<C, Block, AccountId> IvsAggregatorApiServer<<Block as BlockT>::Hash, AccountId>
+    for IvsAggregator<C, Block>
+where
+    Block: BlockT,
+    C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + Send + Sync + 'static,
+    C::Api: IvsAggregatorRuntimeApi<Block, AccountId>,
+    AccountId: Codec + DeserializeOwned + Send + Sync + 'static,
+{
+    fn can_decrypt(
+        &self,
+        requester: AccountId,
+        user: AccountId,
+        at: Option<Block::Hash>,
+    ) -> RpcResult<bool> {
+        let at_hash = at.unwrap_or_else(|| self.client.info().best_hash);
+
+        self.client
+            .runtime_api()
+            .can_decrypt(at_hash, requester, user)
+            .map_err(|e| runtime_error("Unable to check decryption authorization.", e))
+    }
+}
@@ -0,0 +1,51 @@
+//! # IVS Aggregator Runtime API
+//!
+//! Read-only queries over the aggregator pallet for workers and dashboards.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use codec::Codec;
+use pallet_ivs_aggregator::{DecryptionDecision, DecryptionRequestView, RequesterStats};
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for querying aggregator state
+    pub trait IvsAggregatorApi<AccountId>
+    where
+        AccountId: Codec,
+    {
+        /// Recompute statistics for a requester
+        fn requester_stats(requester: Vec<u8>) -> RequesterStats;
+
+        /// Id the next recompute request will be assigned
+        fn next_request_id_peek() -> u64;
+
+        /// Whether `requester` may decrypt `user`'s data
+        fn can_decrypt(requester: AccountId, user: AccountId) -> bool;
+
+        /// Whether `requester` may decrypt `user`'s data, and why not if denied
+        fn decryption_check(requester: AccountId, user: AccountId) -> DecryptionDecision;
+
+        /// Whether `requester` may decrypt each of `users` (bounded by `MaxDecryptionBatch`)
+        fn can_decrypt_batch(requester: AccountId, users: Vec<AccountId>) -> Vec<(AccountId, bool)>;
+
+        /// A decryption session with its contributors and remaining shares,
+        /// or `None` for an unknown id
+        fn decryption_request(session_id: u64) -> Option<DecryptionRequestView<AccountId>>;
+
+        /// Joint public key CIDs with the block each was set at, oldest first
+        fn key_history() -> Vec<(Vec<u8>, u64)>;
+
+        /// Number of committee members
+        fn committee_size() -> u32;
+
+        /// Whether enough committee members are active to meet the global
+        /// decryption policy's threshold
+        fn has_decryption_quorum() -> bool;
+
+        /// Number of recompute requests not yet finished
+        fn pending_request_count() -> u32;
+    }
+}
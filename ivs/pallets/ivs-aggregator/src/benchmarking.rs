@@ -0,0 +1,558 @@
+//! Benchmarking setup for pallet-ivs-aggregator
+
+use super::*;
+use frame_benchmarking::v2::*;
+use frame_support::traits::{EnsureOrigin, Get};
+use frame_system::{pallet_prelude::BlockNumberFor, RawOrigin};
+use sp_runtime::SaturatedConversion;
+use sp_std::{vec, vec::Vec};
+
+const SEED: u32 = 0;
+
+/// A well-formed CIDv0 accepted by the default `AllowedCidPrefixes`
+fn cid() -> Vec<u8> {
+    let mut cid = b"Qm".to_vec();
+    cid.resize(ivs_primitives::CID_V0_LEN, b'a');
+    cid
+}
+
+/// `d` distinct maximum-length disease ids
+fn diseases(d: u32) -> Vec<Vec<u8>> {
+    (0..d)
+        .map(|i| {
+            let mut id = vec![b'd'; 63];
+            id.push(i as u8);
+            id
+        })
+        .collect()
+}
+
+fn member<T: Config>(i: u32) -> T::AccountId {
+    account("member", i, SEED)
+}
+
+/// Add `n` committee members and move past their grace period
+fn add_members<T: Config>(n: u32) {
+    let now = frame_system::Pallet::<T>::block_number().saturated_into::<u64>();
+    for i in 0..n {
+        Pallet::<T>::add_committee_member(
+            RawOrigin::Root.into(),
+            member::<T>(i),
+            vec![b'n'; 64],
+            vec![b'k'; 128],
+            CommitteeRole::Decryptor,
+        )
+        .expect("committee has room; qed");
+    }
+    set_block::<T>(now.saturating_add(T::CommitteeGracePeriod::get()).saturating_add(1));
+}
+
+fn set_block<T: Config>(n: u64) {
+    frame_system::Pallet::<T>::set_block_number(n.saturated_into::<BlockNumberFor<T>>());
+}
+
+/// Queue a pending recompute request from `who` and return its id
+fn pending_request<T: Config>(who: &T::AccountId) -> u64 {
+    Pallet::<T>::request_recompute(
+        RawOrigin::Signed(who.clone()).into(),
+        diseases(1),
+        None,
+        None,
+        None,
+    )
+    .expect("request is valid; qed");
+    NextRequestId::<T>::get() - 1
+}
+
+fn compute_origin<T: Config>() -> Result<T::RuntimeOrigin, BenchmarkError> {
+    T::ComputeOrigin::try_successful_origin().map_err(|_| BenchmarkError::Weightless)
+}
+
+#[benchmarks]
+mod benchmarks {
+    use super::*;
+
+    #[benchmark]
+    fn add_committee_member() {
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        add_committee_member(
+            RawOrigin::Root,
+            account.clone(),
+            vec![b'n'; 64],
+            vec![b'k'; 128],
+            CommitteeRole::Decryptor,
+        );
+
+        assert!(Committee::<T>::contains_key(&account));
+    }
+
+    #[benchmark]
+    fn request_recompute(d: Linear<1, { T::MaxDiseases::get() }>) {
+        let caller: T::AccountId = whitelisted_caller();
+
+        #[extrinsic_call]
+        request_recompute(
+            RawOrigin::Signed(caller),
+            diseases(d),
+            Some(vec![b'u'; 128]),
+            Some(0),
+            Some(vec![b'p'; 256]),
+        );
+
+        assert_eq!(NextRequestId::<T>::get(), 1);
+    }
+
+    #[benchmark]
+    fn store_aggregated_ivs(d: Linear<1, { T::MaxDiseases::get() }>) -> Result<(), BenchmarkError> {
+        let origin = compute_origin::<T>()?;
+        let user: T::AccountId = account("user", 0, SEED);
+
+        #[extrinsic_call]
+        _(
+            origin as T::RuntimeOrigin,
+            user.clone(),
+            cid(),
+            diseases(d),
+            vec![b'p'; 256],
+            vec![b'e'; 32],
+            ScoringParams { dmax: 3, model_version: 1, decay_halflife_blocks: 14_400 },
+        );
+
+        assert!(AggregatedIVSScores::<T>::contains_key(&user));
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_decryption_policy(a: Linear<0, 16>) {
+        let size = a.max(T::MinCommitteeForPolicy::get()).max(1);
+        add_members::<T>(size);
+        let accounts: Vec<T::AccountId> = (0..a).map(member::<T>).collect();
+
+        #[extrinsic_call]
+        set_decryption_policy(RawOrigin::Root, accounts, 1, size, None);
+
+        assert!(CurrentDecryptionPolicy::<T>::get().is_some());
+    }
+
+    #[benchmark]
+    fn update_joint_public_key() {
+        // Worst case: the history is full and the oldest entry is evicted
+        for _ in 0..32 {
+            Pallet::<T>::update_joint_public_key(RawOrigin::Root.into(), cid())
+                .expect("cid is valid; qed");
+        }
+
+        #[extrinsic_call]
+        update_joint_public_key(RawOrigin::Root, cid());
+
+        assert_eq!(JointKeyVersion::<T>::get(), 33);
+    }
+
+    #[benchmark]
+    fn complete_recompute_request() {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = pending_request::<T>(&caller);
+
+        #[extrinsic_call]
+        complete_recompute_request(RawOrigin::Root, request_id, Some(cid()), Some(cid()), None);
+
+        assert_eq!(LastCompletedRequestId::<T>::get(), Some(request_id));
+    }
+
+    // `n` pending requests from distinct requesters are completed.
+    #[benchmark]
+    fn complete_recompute_requests_batch(n: Linear<1, { T::MaxCompletionBatch::get() }>) {
+        let request_ids: Vec<u64> = (0..n)
+            .map(|i| pending_request::<T>(&account("requester", i, SEED)))
+            .collect();
+
+        #[extrinsic_call]
+        complete_recompute_requests_batch(RawOrigin::Root, request_ids);
+
+        assert_eq!(LastCompletedRequestId::<T>::get(), Some(n as u64 - 1));
+    }
+
+    #[benchmark]
+    fn set_request_manifest() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = pending_request::<T>(&caller);
+        Pallet::<T>::claim_recompute_request(compute_origin::<T>()?, request_id)
+            .expect("request is pending; qed");
+        let origin = compute_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, request_id, cid());
+
+        assert!(RecomputeRequests::<T>::get(request_id).unwrap().manifest_cid.is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn retry_recompute_request() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = pending_request::<T>(&caller);
+        Pallet::<T>::fail_recompute_request(RawOrigin::Root.into(), request_id, vec![b'r'; 256])
+            .expect("request is pending; qed");
+        let origin = compute_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, request_id);
+
+        assert_eq!(RecomputeRequests::<T>::get(request_id).unwrap().retry_count, 1);
+        Ok(())
+    }
+
+    #[benchmark]
+    fn schedule_committee_reset() {
+        #[extrinsic_call]
+        schedule_committee_reset(RawOrigin::Root);
+
+        assert!(CommitteeResetAvailableAt::<T>::get().is_some());
+    }
+
+    // `o` old members are replaced by `m` new members.
+    #[benchmark]
+    fn execute_committee_reset(
+        o: Linear<0, { T::MaxCommitteeSize::get() }>,
+        m: Linear<0, { T::MaxCommitteeSize::get() }>,
+    ) {
+        add_members::<T>(o);
+        CommitteeResetAvailableAt::<T>::put(0);
+        let new_members: Vec<_> = (0..m)
+            .map(|i| (account("new", i, SEED), vec![b'n'; 64], vec![b'k'; 128]))
+            .collect();
+
+        #[extrinsic_call]
+        execute_committee_reset(RawOrigin::Root, new_members);
+
+        assert_eq!(CommitteeSize::<T>::get(), m);
+    }
+
+    // `s` entries in each scanned map.
+    #[benchmark]
+    fn verify_invariants(s: Linear<0, { T::MaxInvariantScan::get().min(T::MaxCommitteeSize::get()) }>) {
+        add_members::<T>(s);
+        for i in 0..s {
+            pending_request::<T>(&account("requester", i, SEED));
+        }
+
+        #[extrinsic_call]
+        verify_invariants(RawOrigin::Root);
+    }
+
+    // `m` members are counted to check the decryption threshold.
+    #[benchmark]
+    fn remove_committee_member(m: Linear<1, { T::MaxCommitteeSize::get() }>) {
+        let size = m.max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![], 1, size, None)
+            .expect("committee is large enough; qed");
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        remove_committee_member(RawOrigin::Root, account.clone());
+
+        assert!(!Committee::<T>::contains_key(&account));
+    }
+
+    // `m` members are counted to check the decryption threshold.
+    #[benchmark]
+    fn set_committee_member_active(m: Linear<1, { T::MaxCommitteeSize::get() }>) {
+        let size = m.max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![], 1, size, None)
+            .expect("committee is large enough; qed");
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        set_committee_member_active(RawOrigin::Root, account.clone(), false);
+
+        assert!(!Committee::<T>::get(&account).unwrap().is_active);
+    }
+
+    #[benchmark]
+    fn committee_heartbeat() {
+        add_members::<T>(1);
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        committee_heartbeat(RawOrigin::Signed(account.clone()));
+
+        let now = frame_system::Pallet::<T>::block_number().saturated_into::<u64>();
+        assert_eq!(Committee::<T>::get(&account).unwrap().last_seen, now);
+    }
+
+    // `l` stale members are inspected; `m` members are counted to check the
+    // decryption threshold.
+    #[benchmark]
+    fn deactivate_stale_committee(
+        l: Linear<1, { T::MaxCommitteeSize::get() }>,
+        m: Linear<1, { T::MaxCommitteeSize::get() }>,
+    ) {
+        let size = l.max(m).max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![], 1, size, None)
+            .expect("committee is large enough; qed");
+
+        #[extrinsic_call]
+        deactivate_stale_committee(RawOrigin::Root, 0, l);
+
+        assert!(Pallet::<T>::has_decryption_quorum());
+    }
+
+    #[benchmark]
+    fn cancel_recompute_request() {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = pending_request::<T>(&caller);
+
+        #[extrinsic_call]
+        cancel_recompute_request(RawOrigin::Signed(caller), request_id);
+
+        assert!(matches!(
+            RecomputeRequests::<T>::get(request_id).unwrap().status,
+            RequestStatus::Cancelled
+        ));
+    }
+
+    #[benchmark]
+    fn add_allowed_requester() {
+        let account: T::AccountId = account("requester", 0, SEED);
+
+        #[extrinsic_call]
+        add_allowed_requester(RawOrigin::Root, account.clone());
+
+        assert!(RequesterAllowlist::<T>::contains_key(&account));
+    }
+
+    #[benchmark]
+    fn remove_allowed_requester() {
+        let account: T::AccountId = account("requester", 0, SEED);
+        RequesterAllowlist::<T>::insert(&account, ());
+
+        #[extrinsic_call]
+        remove_allowed_requester(RawOrigin::Root, account.clone());
+
+        assert!(!RequesterAllowlist::<T>::contains_key(&account));
+    }
+
+    #[benchmark]
+    fn fail_recompute_request() {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = pending_request::<T>(&caller);
+
+        #[extrinsic_call]
+        fail_recompute_request(RawOrigin::Root, request_id, vec![b'r'; 256]);
+
+        assert!(RecomputeRequests::<T>::get(request_id).unwrap().failure_reason.is_some());
+    }
+
+    #[benchmark]
+    fn claim_recompute_request() -> Result<(), BenchmarkError> {
+        let caller: T::AccountId = whitelisted_caller();
+        let request_id = pending_request::<T>(&caller);
+        let origin = compute_origin::<T>()?;
+
+        #[extrinsic_call]
+        _(origin as T::RuntimeOrigin, request_id);
+
+        assert!(RecomputeRequests::<T>::get(request_id).unwrap().claimed_at.is_some());
+        Ok(())
+    }
+
+    #[benchmark]
+    fn set_committee_term_end() {
+        add_members::<T>(1);
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        set_committee_term_end(RawOrigin::Root, account.clone(), Some(1_000));
+
+        assert_eq!(Committee::<T>::get(&account).unwrap().term_end, Some(1_000));
+    }
+
+    #[benchmark]
+    fn set_user_decryption_policy(a: Linear<0, 16>) {
+        let size = a.max(T::MinCommitteeForPolicy::get()).max(1);
+        add_members::<T>(size);
+        let user: T::AccountId = account("user", 0, SEED);
+        let accounts: Vec<T::AccountId> = (0..a).map(member::<T>).collect();
+
+        #[extrinsic_call]
+        set_user_decryption_policy(RawOrigin::Root, user.clone(), accounts, 1, size, None);
+
+        assert!(UserDecryptionPolicies::<T>::contains_key(&user));
+    }
+
+    // `m` members are counted to check quorum.
+    #[benchmark]
+    fn open_decryption_session(m: Linear<1, { T::MaxCommitteeSize::get() }>) {
+        let size = m.max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        let caller = member::<T>(0);
+        let user: T::AccountId = account("user", 0, SEED);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![caller.clone()], 1, size, None)
+            .expect("committee is large enough; qed");
+
+        #[extrinsic_call]
+        open_decryption_session(RawOrigin::Signed(caller), user);
+
+        assert!(DecryptionSessions::<T>::contains_key(0));
+    }
+
+    // `s` shares were already submitted; the new share completes the session.
+    #[benchmark]
+    fn submit_decryption_share(s: Linear<0, { T::MaxCommitteeSize::get() - 1 }>) {
+        let size = (s + 1).max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        let caller = member::<T>(0);
+        let user: T::AccountId = account("user", 0, SEED);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![caller.clone()], s + 1, size, None)
+            .expect("committee is large enough; qed");
+        Pallet::<T>::open_decryption_session(RawOrigin::Signed(caller).into(), user)
+            .expect("caller is authorized; qed");
+        for i in 0..s {
+            Pallet::<T>::submit_decryption_share(RawOrigin::Signed(member::<T>(i)).into(), 0, vec![b's'; 256])
+                .expect("member is active; qed");
+        }
+
+        #[extrinsic_call]
+        submit_decryption_share(RawOrigin::Signed(member::<T>(s)), 0, vec![b's'; 256]);
+
+        assert!(DecryptionSessions::<T>::get(0).unwrap().completed);
+    }
+
+    #[benchmark]
+    fn reactivate_committee_member() {
+        add_members::<T>(1);
+        let account = member::<T>(0);
+        Committee::<T>::mutate(&account, |m| m.as_mut().unwrap().is_active = false);
+
+        #[extrinsic_call]
+        reactivate_committee_member(RawOrigin::Root, account.clone());
+
+        assert!(Committee::<T>::get(&account).unwrap().is_active);
+    }
+
+    #[benchmark]
+    fn rotate_key_share() {
+        add_members::<T>(1);
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        rotate_key_share(RawOrigin::Root, account.clone(), vec![b'r'; 128]);
+
+        assert_eq!(Committee::<T>::get(&account).unwrap().key_version, 1);
+    }
+
+    #[benchmark]
+    fn update_committee_member_name() {
+        add_members::<T>(1);
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        update_committee_member_name(RawOrigin::Root, account.clone(), vec![b'r'; 64]);
+
+        assert_eq!(Committee::<T>::get(&account).unwrap().name.len(), 64);
+    }
+
+    #[benchmark]
+    fn pause_recompute() {
+        #[extrinsic_call]
+        pause_recompute(RawOrigin::Root);
+
+        assert!(RecomputePaused::<T>::get());
+    }
+
+    #[benchmark]
+    fn unpause_recompute() {
+        RecomputePaused::<T>::put(true);
+
+        #[extrinsic_call]
+        unpause_recompute(RawOrigin::Root);
+
+        assert!(!RecomputePaused::<T>::get());
+    }
+
+    #[benchmark]
+    fn set_disease_cadence() {
+        #[extrinsic_call]
+        set_disease_cadence(RawOrigin::Root, vec![b'd'; 64], Some(100));
+    }
+
+    #[benchmark]
+    fn register_joint_public_key() {
+        #[extrinsic_call]
+        register_joint_public_key(RawOrigin::Root, 1, cid());
+
+        assert!(JointPublicKeys::<T>::contains_key(1));
+    }
+
+    #[benchmark]
+    fn set_current_joint_key_version() {
+        // Worst case: the history is full and the oldest entry is evicted
+        for _ in 0..32 {
+            Pallet::<T>::update_joint_public_key(RawOrigin::Root.into(), cid())
+                .expect("cid is valid; qed");
+        }
+        Pallet::<T>::register_joint_public_key(RawOrigin::Root.into(), 100, cid())
+            .expect("version is free; qed");
+
+        #[extrinsic_call]
+        set_current_joint_key_version(RawOrigin::Root, 100);
+
+        assert_eq!(CurrentJointKeyVersion::<T>::get(), 100);
+    }
+
+    // `m` members are counted to check the decryption threshold.
+    #[benchmark]
+    fn record_decryption_access(m: Linear<1, { T::MaxCommitteeSize::get() }>) {
+        let size = m.max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        let caller = member::<T>(0);
+        let user: T::AccountId = account("user", 0, SEED);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![caller.clone()], 1, size, None)
+            .expect("committee is large enough; qed");
+        // Worst case: the log is full and the oldest entry is dropped
+        NextAuditIndex::<T>::put(T::MaxAuditLogEntries::get() as u64);
+
+        #[extrinsic_call]
+        record_decryption_access(RawOrigin::Signed(caller), user);
+
+        assert_eq!(NextAuditIndex::<T>::get(), T::MaxAuditLogEntries::get() as u64 + 1);
+    }
+
+    // `m` members are counted to check the decryption threshold.
+    #[benchmark]
+    fn resign_committee(m: Linear<1, { T::MaxCommitteeSize::get() }>) {
+        let size = m.max(T::MinCommitteeForPolicy::get());
+        add_members::<T>(size);
+        Pallet::<T>::set_decryption_policy(RawOrigin::Root.into(), vec![], 1, size, None)
+            .expect("committee is large enough; qed");
+        let account = member::<T>(0);
+
+        #[extrinsic_call]
+        resign_committee(RawOrigin::Signed(account.clone()));
+
+        assert!(!Committee::<T>::get(&account).unwrap().is_active);
+    }
+
+    // `m` members are counted; the caller's flag reaches the majority.
+    #[benchmark]
+    fn flag_key_compromise(m: Linear<1, { T::MaxCommitteeSize::get() }>) {
+        add_members::<T>(m);
+        for i in 1..=m / 2 {
+            Pallet::<T>::flag_key_compromise(RawOrigin::Signed(member::<T>(i)).into())
+                .expect("member has not flagged yet; qed");
+        }
+        let caller = member::<T>(0);
+
+        #[extrinsic_call]
+        flag_key_compromise(RawOrigin::Signed(caller.clone()));
+
+        assert!(KeyCompromiseFlags::<T>::contains_key(&caller));
+    }
+
+    impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -15,12 +15,24 @@
 
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
     use frame_support::pallet_prelude::*;
     use frame_system::pallet_prelude::*;
     use sp_std::vec::Vec;
 
+    use crate::weights::WeightInfo;
+
     /// Committee member for threshold cryptography
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
     pub struct CommitteeMember<AccountId> {
@@ -34,6 +46,41 @@ pub mod pallet {
         pub is_active: bool,
         /// Joined at block number
         pub joined_at: u64,
+        /// Block from which the member counts toward quorum
+        pub active_after: u64,
+        /// Block of the member's latest heartbeat
+        pub last_seen: u64,
+        /// Block at which the member's term ends (None = no term limit)
+        pub term_end: Option<u64>,
+        /// Key share version, bumped on every rotation
+        pub key_version: u32,
+        /// Block of the latest key share rotation
+        pub key_rotated_at: Option<u64>,
+        /// Actions the member may perform
+        pub role: CommitteeRole,
+    }
+
+    /// Committee member role
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub enum CommitteeRole {
+        /// Submits decryption shares
+        #[default]
+        Decryptor,
+        /// Approves committee decisions
+        Approver,
+        /// Read-only member
+        Observer,
+    }
+
+    /// Scoring parameters an aggregation was computed with
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct ScoringParams {
+        /// Maximum contact distance considered (Dmax)
+        pub dmax: u32,
+        /// Version of the scoring model
+        pub model_version: u16,
+        /// Half-life in blocks of the exposure decay
+        pub decay_halflife_blocks: u32,
     }
 
     /// Aggregated IVS record (encrypted, cross-disease)
@@ -47,6 +94,13 @@ pub mod pallet {
         pub computed_at: u64,
         /// Computation parameters (JSON metadata)
         pub parameters: BoundedVec<u8, ConstU32<256>>,
+        /// Typed scoring parameters (zero for pre-v3 records)
+        pub scoring: ScoringParams,
+        /// Compute engine that produced the aggregation (e.g. MHE library;
+        /// `migrations::v1::LEGACY_ENGINE_ID` for baseline records)
+        pub engine_id: BoundedVec<u8, ConstU32<32>>,
+        /// Recompute request that produced the aggregation, if any
+        pub request_id: Option<u64>,
     }
 
     /// Recompute request
@@ -64,6 +118,22 @@ pub mod pallet {
         pub requested_at: u64,
         /// Status: Pending, InProgress, Completed, Failed
         pub status: RequestStatus,
+        /// IPFS CID of the input manifest the worker computed from
+        pub manifest_cid: Option<BoundedVec<u8, ConstU32<128>>>,
+        /// Number of times the request was re-queued after failing
+        pub retry_count: u32,
+        /// Earliest block at which a re-queued request may be picked up
+        pub next_retry_at: Option<u64>,
+        /// IPFS CID of the correctness proof attached on completion
+        pub proof_cid: Option<BoundedVec<u8, ConstU32<128>>>,
+        /// Why the compute network marked the request failed
+        pub failure_reason: Option<BoundedVec<u8, ConstU32<256>>>,
+        /// Block at which a worker claimed the request
+        pub claimed_at: Option<u64>,
+        /// Algorithm parameters for the worker (e.g. Dmax, scoring model version)
+        pub algorithm_params: Option<BoundedVec<u8, ConstU32<256>>>,
+        /// IPFS CID of the encrypted output recorded on completion
+        pub result_cid: Option<BoundedVec<u8, ConstU32<128>>>,
     }
 
     #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
@@ -72,6 +142,67 @@ pub mod pallet {
         InProgress,
         Completed,
         Failed,
+        Cancelled,
+    }
+
+    /// Recompute statistics maintained per requester
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen, Default)]
+    pub struct RequesterStats {
+        /// Requests ever submitted
+        pub total_requested: u32,
+        /// Requests not yet finished (Pending or InProgress)
+        pub pending: u32,
+        /// Requests completed
+        pub completed: u32,
+        /// Requests currently failed
+        pub failed: u32,
+        /// Requests cancelled by the requester
+        pub cancelled: u32,
+    }
+
+    impl RequesterStats {
+        fn counter(&mut self, status: &RequestStatus) -> &mut u32 {
+            match status {
+                RequestStatus::Pending | RequestStatus::InProgress => &mut self.pending,
+                RequestStatus::Completed => &mut self.completed,
+                RequestStatus::Failed => &mut self.failed,
+                RequestStatus::Cancelled => &mut self.cancelled,
+            }
+        }
+
+        /// Move one request from the `from` bucket to the `to` bucket
+        pub fn note_transition(&mut self, from: &RequestStatus, to: &RequestStatus) {
+            let from_counter = self.counter(from);
+            *from_counter = from_counter.saturating_sub(1);
+            let to_counter = self.counter(to);
+            *to_counter = to_counter.saturating_add(1);
+        }
+    }
+
+    /// Storage invariant reported by `verify_invariants`
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum InvariantCode {
+        /// `CommitteeSize` differs from the number of `Committee` entries
+        CommitteeSizeMismatch,
+        /// Requester statistics do not add up to the stored requests
+        RequestCountMismatch,
+        /// A stored request id is not below `NextRequestId`
+        NextRequestIdBehind,
+    }
+
+    /// Outcome of a decryption authorization check
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+    pub enum DecryptionDecision {
+        /// Requester may decrypt
+        Allowed,
+        /// Policy has expired
+        Expired,
+        /// Requester is not in the policy
+        NotAuthorized,
+        /// No decryption policy is set
+        NoPolicy,
+        /// Too few active committee members to meet the threshold
+        NoQuorum,
     }
 
     /// Threshold decryption policy
@@ -89,9 +220,64 @@ pub mod pallet {
         pub audit_enabled: bool,
     }
 
+    /// Threshold decryption session collecting partial decryption shares
+    #[derive(
+        CloneNoBound, Encode, Decode, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+        MaxEncodedLen,
+    )]
+    #[scale_info(skip_type_params(MaxShares))]
+    #[codec(mel_bound(AccountId: MaxEncodedLen))]
+    pub struct DecryptionSession<
+        AccountId: Clone + Eq + core::fmt::Debug,
+        MaxShares: Get<u32>,
+    > {
+        /// Session ID
+        pub session_id: u64,
+        /// User whose data is being decrypted
+        pub target_user: AccountId,
+        /// Account that opened the session
+        pub requester: AccountId,
+        /// Partial decryption shares submitted by committee members
+        pub submitted_shares: BoundedVec<(AccountId, BoundedVec<u8, ConstU32<256>>), MaxShares>,
+        /// Session opened at block number
+        pub created_at: u64,
+        /// Enough shares have been collected
+        pub completed: bool,
+    }
+
+    /// Flattened view of a decryption session for auditors
+    #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+    pub struct DecryptionRequestView<AccountId> {
+        /// Session ID
+        pub session_id: u64,
+        /// Account that opened the session
+        pub requester: AccountId,
+        /// User whose data is being decrypted
+        pub target_user: AccountId,
+        /// Session opened at block number
+        pub created_at: u64,
+        /// Committee members that submitted a share, in submission order
+        pub contributors: Vec<AccountId>,
+        /// Enough shares have been collected
+        pub completed: bool,
+        /// Shares still needed to reach the applicable policy's threshold
+        pub shares_remaining: u32,
+    }
+
+    /// `ComputeOrigin` for runtimes without a dedicated compute-network account
+    pub type DefaultComputeOrigin<T> =
+        frame_system::EnsureRoot<<T as frame_system::Config>::AccountId>;
+
     #[pallet::config]
     pub trait Config: frame_system::Config {
         type RuntimeEvent: From<Event<Self>> + IsType<<Self as frame_system::Config>::RuntimeEvent>;
+
+        /// Weight information for extrinsics in this pallet
+        type WeightInfo: WeightInfo;
+
+        /// Origin of the off-chain compute network, allowed to store results
+        /// and drive recompute requests (see `DefaultComputeOrigin`)
+        type ComputeOrigin: EnsureOrigin<Self::RuntimeOrigin>;
         
         /// Maximum number of committee members
         #[pallet::constant]
@@ -100,9 +286,77 @@ pub mod pallet {
         /// Maximum diseases in aggregation
         #[pallet::constant]
         type MaxDiseases: Get<u32>;
+
+        /// Blocks a new committee member has to provision its key share
+        /// before it counts toward quorum
+        #[pallet::constant]
+        type CommitteeGracePeriod: Get<u64>;
+
+        /// Base delay in blocks for retry backoff (doubles per retry)
+        #[pallet::constant]
+        type RetryBackoffBase: Get<u64>;
+
+        /// Maximum retries before a failed request stays failed
+        #[pallet::constant]
+        type MaxRetries: Get<u32>;
+
+        /// Blocks between scheduling and executing an emergency committee reset
+        #[pallet::constant]
+        type ResetTimelock: Get<u64>;
+
+        /// Minimum committee size before a decryption policy may be set
+        #[pallet::constant]
+        type MinCommitteeForPolicy: Get<u32>;
+
+        /// Maximum entries per map scanned by `verify_invariants`
+        #[pallet::constant]
+        type MaxInvariantScan: Get<u32>;
+
+        /// CID prefixes accepted by `validate_cid` (see `ivs_primitives::DefaultCidPrefixes`)
+        type AllowedCidPrefixes: Get<&'static [&'static [u8]]>;
+
+        /// Maximum users evaluated by `can_decrypt_batch`
+        #[pallet::constant]
+        type MaxDecryptionBatch: Get<u32>;
+
+        /// Recompute cadence in blocks for diseases without their own
+        #[pallet::constant]
+        type DefaultRecomputeCadence: Get<u64>;
+
+        /// Maximum recompute requests an account may submit per window
+        #[pallet::constant]
+        type MaxRequestsPerWindow: Get<u32>;
+
+        /// Length in blocks of the recompute rate-limit window
+        #[pallet::constant]
+        type RequestWindowBlocks: Get<u64>;
+
+        /// Decryption audit entries kept before the oldest is dropped
+        #[pallet::constant]
+        type MaxAuditLogEntries: Get<u32>;
+
+        /// Blocks after which an unfinished recompute request is failed as
+        /// expired (0 disables expiry)
+        #[pallet::constant]
+        type RequestExpiryBlocks: Get<u64>;
+
+        /// Maximum recompute requests inspected for expiry per block
+        #[pallet::constant]
+        type MaxExpiryScan: Get<u32>;
+
+        /// Maximum request ids processed by `complete_recompute_requests_batch`
+        #[pallet::constant]
+        type MaxCompletionBatch: Get<u32>;
     }
 
+    /// Current storage version (1: baseline records translated and joint public
+    /// keys kept in a versioned registry, 2: CID reference counts, 3: typed
+    /// scoring parameters on aggregations, 4: previous-window counts in
+    /// recompute rate limits)
+    pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(4);
+
     #[pallet::pallet]
+    #[pallet::storage_version(STORAGE_VERSION)]
     pub struct Pallet<T>(_);
 
     /// MHE Committee members
@@ -127,6 +381,28 @@ pub mod pallet {
         OptionQuery,
     >;
 
+    /// Past aggregated IVS results per user, oldest first
+    #[pallet::storage]
+    pub type AggregatedIVSHistory<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        BoundedVec<AggregatedIVS, ConstU32<32>>,
+        ValueQuery,
+    >;
+
+    /// Number of `AggregatedIVSHistory` entries referencing each IPFS CID
+    /// (the current score is always the newest entry); a CID is removed once
+    /// nothing references it, so pinners can unpin it
+    #[pallet::storage]
+    pub type CidRefCount<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<128>>,
+        u32,
+        ValueQuery,
+    >;
+
     /// Recompute requests queue
     #[pallet::storage]
     #[pallet::getter(fn recompute_request)]
@@ -143,6 +419,93 @@ pub mod pallet {
     #[pallet::getter(fn next_request_id)]
     pub type NextRequestId<T: Config> = StorageValue<_, u64, ValueQuery>;
 
+    /// Number of recompute requests in `Pending` status
+    #[pallet::storage]
+    pub type PendingRequestCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Whether `request_recompute` is paused
+    #[pallet::storage]
+    #[pallet::getter(fn recompute_paused)]
+    pub type RecomputePaused<T: Config> = StorageValue<_, bool, ValueQuery>;
+
+    /// Committee members who flagged the joint key as compromised → block flagged at
+    #[pallet::storage]
+    #[pallet::getter(fn key_compromise_flag)]
+    pub type KeyCompromiseFlags<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Oldest request id not yet checked for expiry. Ids are assigned in
+    /// `requested_at` order, so ids from here up to `NextRequestId` are the
+    /// expiry index, oldest first.
+    #[pallet::storage]
+    pub type ExpiryCursor<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Threshold decryption sessions
+    #[pallet::storage]
+    #[pallet::getter(fn decryption_session)]
+    pub type DecryptionSessions<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        DecryptionSession<T::AccountId, T::MaxCommitteeSize>,
+        OptionQuery,
+    >;
+
+    /// Next decryption session ID
+    #[pallet::storage]
+    pub type NextSessionId<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Accounts allowed to request recomputes (empty = open to all)
+    #[pallet::storage]
+    #[pallet::getter(fn requester_allowed)]
+    pub type RequesterAllowlist<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (),
+        OptionQuery,
+    >;
+
+    /// Client nonce index: (requester, client_nonce) → request_id
+    ///
+    /// Request ids stay sequential (see `ExpiryCursor`); this index is what
+    /// makes a nonce resolve to the same id on every retry.
+    #[pallet::storage]
+    #[pallet::getter(fn request_by_nonce)]
+    pub type RequestByNonce<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        (T::AccountId, u64),
+        u64,
+        OptionQuery,
+    >;
+
+    /// Recompute rate limit per account: (window start block, requests in
+    /// window, requests in the window before it)
+    #[pallet::storage]
+    pub type RequestRateLimits<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        (u64, u32, u32),
+        ValueQuery,
+    >;
+
+    /// Recompute statistics per requester
+    #[pallet::storage]
+    pub type RequesterStatistics<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<128>>,
+        RequesterStats,
+        ValueQuery,
+    >;
+
     /// Decryption policy
     #[pallet::storage]
     #[pallet::getter(fn decryption_policy)]
@@ -152,33 +515,226 @@ pub mod pallet {
         OptionQuery,
     >;
 
-    /// Joint public key CID
+    /// Per-user decryption policies, taking precedence over the global one
+    #[pallet::storage]
+    #[pallet::getter(fn user_decryption_policy)]
+    pub type UserDecryptionPolicies<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        T::AccountId,
+        DecryptionPolicy<T::AccountId>,
+        OptionQuery,
+    >;
+
+    /// Decryption audit trail: index → (requester, user, block)
+    #[pallet::storage]
+    #[pallet::getter(fn decryption_audit_entry)]
+    pub type DecryptionAuditLog<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u64,
+        (T::AccountId, T::AccountId, u64),
+        OptionQuery,
+    >;
+
+    /// Index the next decryption audit entry will be written at
     #[pallet::storage]
-    #[pallet::getter(fn joint_public_key)]
-    pub type JointPublicKey<T: Config> = StorageValue<_, BoundedVec<u8, ConstU32<128>>, ValueQuery>;
+    #[pallet::getter(fn next_audit_index)]
+    pub type NextAuditIndex<T: Config> = StorageValue<_, u64, ValueQuery>;
+
+    /// Registered joint public key CIDs by key version
+    #[pallet::storage]
+    pub type JointPublicKeys<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        u32,
+        BoundedVec<u8, ConstU32<128>>,
+        OptionQuery,
+    >;
+
+    /// Version of the joint public key new uploads should be encrypted with
+    #[pallet::storage]
+    #[pallet::getter(fn current_joint_key_version)]
+    pub type CurrentJointKeyVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Highest version registered in `JointPublicKeys`
+    #[pallet::storage]
+    pub type LatestJointKeyVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Per-disease recompute cadence in blocks
+    #[pallet::storage]
+    pub type DiseaseCadence<T: Config> = StorageMap<
+        _,
+        Blake2_128Concat,
+        BoundedVec<u8, ConstU32<64>>,
+        u64,
+        OptionQuery,
+    >;
+
+    /// Joint key version, bumped when the joint key or any key share changes
+    #[pallet::storage]
+    #[pallet::getter(fn joint_key_version)]
+    pub type JointKeyVersion<T: Config> = StorageValue<_, u32, ValueQuery>;
+
+    /// Joint public key rotation history: (key CID, set at), oldest first
+    #[pallet::storage]
+    #[pallet::getter(fn key_history)]
+    pub type KeyHistory<T: Config> = StorageValue<
+        _,
+        BoundedVec<(BoundedVec<u8, ConstU32<128>>, u64), ConstU32<32>>,
+        ValueQuery,
+    >;
 
     /// Committee size
     #[pallet::storage]
     #[pallet::getter(fn committee_size)]
     pub type CommitteeSize<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+    /// Most recently completed recompute request
+    #[pallet::storage]
+    #[pallet::getter(fn last_completed_request)]
+    pub type LastCompletedRequestId<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    /// Block from which a scheduled committee reset may be executed
+    #[pallet::storage]
+    #[pallet::getter(fn committee_reset_available_at)]
+    pub type CommitteeResetAvailableAt<T: Config> = StorageValue<_, u64, OptionQuery>;
+
+    #[pallet::genesis_config]
+    #[derive(frame_support::DefaultNoBound)]
+    pub struct GenesisConfig<T: Config> {
+        /// Initial committee: (account, name, key share id)
+        pub committee: Vec<(T::AccountId, Vec<u8>, Vec<u8>)>,
+        /// Initial decryption policy: (authorized accounts, threshold, total shares, expires at)
+        pub decryption_policy: Option<(Vec<T::AccountId>, u32, u32, Option<u64>)>,
+    }
+
+    #[pallet::genesis_build]
+    impl<T: Config> BuildGenesisConfig for GenesisConfig<T> {
+        fn build(&self) {
+            for (account, name, key_share_id) in self.committee.iter() {
+                Pallet::<T>::do_add_committee_member(
+                    account.clone(),
+                    name.clone(),
+                    key_share_id.clone(),
+                    CommitteeRole::Decryptor,
+                )
+                .expect("genesis committee members are unique, valid and within MaxCommitteeSize");
+            }
+
+            if let Some((authorized_accounts, threshold, total_shares, expires_at)) =
+                self.decryption_policy.clone()
+            {
+                let policy = Pallet::<T>::build_decryption_policy(
+                    authorized_accounts,
+                    threshold,
+                    total_shares,
+                    expires_at,
+                )
+                .expect("genesis decryption policy is valid for the genesis committee");
+                CurrentDecryptionPolicy::<T>::put(policy);
+            }
+        }
+    }
+
     #[pallet::event]
     #[pallet::generate_deposit(pub(super) fn deposit_event)]
     pub enum Event<T: Config> {
         /// Committee member added
         CommitteeMemberAdded { account: T::AccountId, name: Vec<u8> },
         /// Recompute requested
-        RecomputeRequested { request_id: u64, requester: Vec<u8>, diseases: Vec<Vec<u8>> },
+        RecomputeRequested {
+            request_id: u64,
+            requester: Vec<u8>,
+            diseases: Vec<Vec<u8>>,
+            algorithm_params: Option<Vec<u8>>,
+            at: u64,
+        },
+        /// Recompute request resubmitted with a known client nonce (no-op)
+        RecomputeDeduplicated { request_id: u64 },
         /// Recompute completed
-        RecomputeCompleted { request_id: u64 },
+        RecomputeCompleted {
+            request_id: u64,
+            proof_cid: Option<Vec<u8>>,
+            result_cid: Option<Vec<u8>>,
+            at: u64,
+        },
         /// Aggregated IVS stored
-        AggregatedIVSStored { user: T::AccountId, cid: Vec<u8>, diseases: Vec<Vec<u8>> },
+        AggregatedIVSStored {
+            user: T::AccountId,
+            cid: Vec<u8>,
+            diseases: Vec<Vec<u8>>,
+            engine_id: Vec<u8>,
+            at: u64,
+        },
         /// Decryption policy updated
         DecryptionPolicyUpdated { threshold: u32, total_shares: u32 },
         /// Joint public key updated
         JointPublicKeyUpdated { cid: Vec<u8> },
+        /// Joint public key registered under a version
+        JointPublicKeyRegistered { version: u32, cid: Vec<u8> },
+        /// Current joint public key version switched
+        CurrentJointKeyVersionSet { version: u32 },
         /// Decryption request authorized
         DecryptionAuthorized { requester: T::AccountId, user: T::AccountId },
+        /// Input manifest recorded for a recompute request
+        RequestManifestSet { request_id: u64 },
+        /// Failed request re-queued with backoff
+        RecomputeRetryScheduled { request_id: u64, retry_count: u32, next_retry_at: u64 },
+        /// Emergency committee reset scheduled
+        CommitteeResetScheduled { available_at: u64 },
+        /// Committee wiped and re-initialized
+        CommitteeReset { members: u32 },
+        /// Storage invariant check failed
+        InvariantViolation { code: InvariantCode },
+        /// Committee member removed
+        CommitteeMemberRemoved { account: T::AccountId, name: Vec<u8> },
+        /// Committee member activated or suspended
+        CommitteeMemberStatusChanged { account: T::AccountId, active: bool },
+        /// Committee member deactivated itself
+        CommitteeMemberResigned { account: T::AccountId },
+        /// Committee member reported liveness
+        CommitteeHeartbeat { account: T::AccountId, at: u64 },
+        /// Stale committee members deactivated
+        StaleCommitteeDeactivated { count: u32 },
+        /// Recompute request withdrawn by its requester
+        RecomputeCancelled { request_id: u64 },
+        /// Account added to the requester allow-list
+        RequesterAllowed { account: T::AccountId },
+        /// Account removed from the requester allow-list
+        RequesterDisallowed { account: T::AccountId },
+        /// Recompute failed
+        RecomputeFailed { request_id: u64, reason: Vec<u8> },
+        /// Unfinished recompute request failed after `RequestExpiryBlocks`
+        RecomputeExpired { request_id: u64 },
+        /// Several recompute requests marked completed in one call
+        RecomputeBatchCompleted { count: u32 },
+        /// Committee member display name changed
+        CommitteeMemberRenamed { account: T::AccountId, name: Vec<u8> },
+        /// New recompute requests paused or resumed
+        RecomputePauseToggled { paused: bool },
+        /// Recompute request claimed by a worker
+        RecomputeClaimed { request_id: u64 },
+        /// Committee member term end updated
+        CommitteeTermSet { account: T::AccountId, term_end: Option<u64> },
+        /// Decryption policy set for a single user
+        UserDecryptionPolicyUpdated { user: T::AccountId, threshold: u32, total_shares: u32 },
+        /// Decryption session opened
+        DecryptionSessionOpened { session_id: u64, requester: T::AccountId, user: T::AccountId },
+        /// Committee member submitted a decryption share
+        DecryptionShareSubmitted { session_id: u64, member: T::AccountId },
+        /// Session collected enough shares to decrypt
+        DecryptionThresholdReached { session_id: u64 },
+        /// Suspended committee member restored after recovery
+        CommitteeMemberReactivated { account: T::AccountId },
+        /// Committee member key share rotated
+        KeyShareRotated { account: T::AccountId, key_version: u32 },
+        /// Recompute cadence set or cleared for a disease
+        DiseaseCadenceSet { disease_id: Vec<u8>, blocks: Option<u64> },
+        /// Committee member flagged the joint key as compromised
+        KeyCompromiseFlagged { member: T::AccountId },
+        /// A majority of the members counting toward quorum flagged the joint key
+        KeyCompromiseDeclared { flags: u32 },
     }
 
     #[pallet::error]
@@ -197,72 +753,135 @@ pub mod pallet {
         InvalidParameters,
         /// Too many diseases
         TooManyDiseases,
+        /// Invalid CID format
+        InvalidCID,
+        /// Request is not in progress
+        RequestNotInProgress,
+        /// Request has not failed
+        RequestNotFailed,
+        /// Request exhausted its retries
+        RetryLimitReached,
+        /// No committee reset is scheduled
+        ResetNotScheduled,
+        /// Committee reset timelock has not elapsed
+        ResetTimelocked,
+        /// Committee too small to satisfy the policy
+        CommitteeTooSmall,
+        /// Change would leave fewer active members than the policy threshold
+        WouldBreakThreshold,
+        /// Request is no longer pending
+        RequestNotPending,
+        /// Request already reached a final status
+        RequestAlreadyFinalized,
+        /// Request was already claimed or is not pending
+        AlreadyClaimed,
+        /// Decryption session not found
+        SessionNotFound,
+        /// Decryption session already reached its threshold
+        SessionCompleted,
+        /// Member already submitted a share for this session
+        DuplicateShare,
+        /// Caller is not an active committee member
+        NotActiveMember,
+        /// Committee member is not suspended
+        NotSuspended,
+        /// Committee member's role does not permit the action
+        WrongRole,
+        /// Too many recompute requests in the current window
+        RateLimited,
+        /// A joint public key is already registered under this version
+        KeyVersionExists,
+        /// No joint public key is registered under this version
+        UnknownKeyVersion,
+        /// New recompute requests are paused
+        RecomputePausedError,
+        /// The old committee could not be cleared within `MaxCommitteeSize` removals
+        CommitteeClearIncomplete,
+        /// Caller already flagged the joint key as compromised
+        AlreadyFlagged,
+    }
+
+    #[pallet::hooks]
+    impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+        fn on_initialize(_n: BlockNumberFor<T>) -> Weight {
+            Self::expire_stale_requests()
+        }
     }
 
     #[pallet::call]
     impl<T: Config> Pallet<T> {
         /// Add committee member
         #[pallet::call_index(0)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::add_committee_member())]
         pub fn add_committee_member(
             origin: OriginFor<T>,
             account: T::AccountId,
             name: Vec<u8>,
             key_share_id: Vec<u8>,
+            role: CommitteeRole,
         ) -> DispatchResult {
             ensure_root(origin)?;
 
-            ensure!(!Committee::<T>::contains_key(&account), Error::<T>::CommitteeMemberExists);
-            
-            let size = CommitteeSize::<T>::get();
-            ensure!(size < T::MaxCommitteeSize::get(), Error::<T>::CommitteeFull);
-
-            let name_bounded: BoundedVec<u8, ConstU32<64>> = 
-                name.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
-            let key_bounded: BoundedVec<u8, ConstU32<128>> = 
-                key_share_id.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
-
-            let member = CommitteeMember {
-                account: account.clone(),
-                name: name_bounded,
-                key_share_id: key_bounded,
-                is_active: true,
-                joined_at: Self::current_timestamp(),
-            };
-
-            Committee::<T>::insert(&account, member);
-            CommitteeSize::<T>::mutate(|s| *s = s.saturating_add(1));
-
-            Self::deposit_event(Event::CommitteeMemberAdded { account, name });
-            Ok(())
+            Self::do_add_committee_member(account, name, key_share_id, role)
         }
 
         /// Request IVS recomputation
+        ///
+        /// Request ids are assigned sequentially from `NextRequestId`, not derived
+        /// from the nonce. When `client_nonce` is provided the first submission
+        /// records its id in `RequestByNonce`, so a retried submission with the
+        /// same nonce is a no-op that re-announces the originally assigned id
+        /// instead of queueing a duplicate. Clients look the id up with
+        /// `request_by_nonce(who, nonce)`.
+        ///
+        /// Each account may queue at most `MaxRequestsPerWindow` requests in any
+        /// `RequestWindowBlocks`-long rolling window, so a burst cannot be
+        /// doubled across a window boundary; deduplicated retries do not count.
+        ///
+        /// `algorithm_params` (at most 256 bytes) is passed through to the
+        /// worker, so a cohort can be re-run with different scoring parameters.
         #[pallet::call_index(1)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::request_recompute(disease_ids.len() as u32))]
         pub fn request_recompute(
             origin: OriginFor<T>,
             disease_ids: Vec<Vec<u8>>,
             target_user: Option<Vec<u8>>,
+            client_nonce: Option<u64>,
+            algorithm_params: Option<Vec<u8>>,
         ) -> DispatchResult {
             let who = ensure_signed(origin)?;
 
+            ensure!(!RecomputePaused::<T>::get(), Error::<T>::RecomputePausedError);
+            ensure!(
+                RequesterAllowlist::<T>::iter_keys().next().is_none()
+                    || RequesterAllowlist::<T>::contains_key(&who),
+                Error::<T>::NotAuthorized
+            );
+
+            if let Some(nonce) = client_nonce {
+                if let Some(request_id) = RequestByNonce::<T>::get((who.clone(), nonce)) {
+                    Self::deposit_event(Event::RecomputeDeduplicated { request_id });
+                    return Ok(());
+                }
+            }
+
+            Self::note_request_rate(&who)?;
+
             let request_id = NextRequestId::<T>::get();
             NextRequestId::<T>::mutate(|id| *id = id.saturating_add(1));
 
-            let requester: BoundedVec<u8, ConstU32<128>> = 
-                format!("{:?}", who).as_bytes().to_vec()
-                    .try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+            let requester = Self::requester_key(&who)?;
 
             let target_bounded = target_user.map(|u| {
                 u.try_into().map_err(|_| Error::<T>::InvalidParameters)
             }).transpose()?;
 
-            let diseases_bounded: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<16>> = 
-                disease_ids.iter()
-                    .map(|d| d.clone().try_into().map_err(|_| Error::<T>::TooManyDiseases))
-                    .collect::<Result<Vec<_>, _>>()?
-                    .try_into().map_err(|_| Error::<T>::TooManyDiseases)?;
+            let diseases_bounded = Self::bound_disease_ids(&disease_ids)?;
+
+            let params_bounded: Option<BoundedVec<u8, ConstU32<256>>> = algorithm_params
+                .clone()
+                .map(|params| params.try_into().map_err(|_| Error::<T>::InvalidParameters))
+                .transpose()?;
 
             let request = RecomputeRequest {
                 request_id,
@@ -271,62 +890,67 @@ pub mod pallet {
                 disease_ids: diseases_bounded.clone(),
                 requested_at: Self::current_timestamp(),
                 status: RequestStatus::Pending,
+                manifest_cid: None,
+                retry_count: 0,
+                next_retry_at: None,
+                proof_cid: None,
+                failure_reason: None,
+                claimed_at: None,
+                algorithm_params: params_bounded,
+                result_cid: None,
             };
 
             RecomputeRequests::<T>::insert(request_id, request);
+            PendingRequestCount::<T>::mutate(|count| *count = count.saturating_add(1));
+            RequesterStatistics::<T>::mutate(&requester, |stats| {
+                stats.total_requested = stats.total_requested.saturating_add(1);
+                stats.pending = stats.pending.saturating_add(1);
+            });
+            if let Some(nonce) = client_nonce {
+                RequestByNonce::<T>::insert((who, nonce), request_id);
+            }
 
             Self::deposit_event(Event::RecomputeRequested {
                 request_id,
                 requester: requester.to_vec(),
                 diseases: disease_ids,
+                algorithm_params,
+                at: Self::current_timestamp(),
             });
             Ok(())
         }
 
-        /// Store aggregated IVS result (called by compute network)
+        /// Store aggregated IVS result (called by compute network).
+        ///
+        /// Gated on `ComputeOrigin`; the origin check adds no storage access
+        /// with `EnsureRoot`, so the weight is unchanged.
         #[pallet::call_index(2)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::store_aggregated_ivs(disease_ids.len() as u32))]
         pub fn store_aggregated_ivs(
             origin: OriginFor<T>,
             user: T::AccountId,
             cid: Vec<u8>,
             disease_ids: Vec<Vec<u8>>,
             parameters: Vec<u8>,
+            engine_id: Vec<u8>,
+            scoring: ScoringParams,
         ) -> DispatchResult {
-            ensure_root(origin)?;
-
-            let cid_bounded: BoundedVec<u8, ConstU32<128>> = 
-                cid.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
-
-            let diseases_bounded: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<16>> = 
-                disease_ids.iter()
-                    .map(|d| d.clone().try_into().map_err(|_| Error::<T>::TooManyDiseases))
-                    .collect::<Result<Vec<_>, _>>()?
-                    .try_into().map_err(|_| Error::<T>::TooManyDiseases)?;
-
-            let params_bounded: BoundedVec<u8, ConstU32<256>> = 
-                parameters.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
-
-            let aggregated = AggregatedIVS {
-                cid: cid_bounded,
-                disease_ids: diseases_bounded.clone(),
-                computed_at: Self::current_timestamp(),
-                parameters: params_bounded,
-            };
+            T::ComputeOrigin::ensure_origin(origin)?;
 
-            AggregatedIVSScores::<T>::insert(&user, aggregated);
-
-            Self::deposit_event(Event::AggregatedIVSStored {
+            Self::do_store_aggregated_ivs(
                 user,
                 cid,
-                diseases: disease_ids,
-            });
-            Ok(())
+                disease_ids,
+                parameters,
+                engine_id,
+                scoring,
+                None,
+            )
         }
 
         /// Update decryption policy
         #[pallet::call_index(3)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::set_decryption_policy(authorized_accounts.len() as u32))]
         pub fn set_decryption_policy(
             origin: OriginFor<T>,
             authorized_accounts: Vec<T::AccountId>,
@@ -336,16 +960,8 @@ pub mod pallet {
         ) -> DispatchResult {
             ensure_root(origin)?;
 
-            let accounts_bounded: BoundedVec<T::AccountId, ConstU32<16>> = 
-                authorized_accounts.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
-
-            let policy = DecryptionPolicy {
-                authorized_accounts: accounts_bounded,
-                threshold,
-                total_shares,
-                expires_at,
-                audit_enabled: true,
-            };
+            let policy =
+                Self::build_decryption_policy(authorized_accounts, threshold, total_shares, expires_at)?;
 
             CurrentDecryptionPolicy::<T>::put(policy);
 
@@ -356,65 +972,1420 @@ pub mod pallet {
             Ok(())
         }
 
-        /// Update joint public key
+        /// Update joint public key, registering it as the version after the
+        /// highest registered one and switching to it, so keys pre-registered
+        /// with `register_joint_public_key` are never overwritten
         #[pallet::call_index(4)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(T::WeightInfo::update_joint_public_key())]
         pub fn update_joint_public_key(
             origin: OriginFor<T>,
             cid: Vec<u8>,
         ) -> DispatchResult {
             ensure_root(origin)?;
 
-            let cid_bounded: BoundedVec<u8, ConstU32<128>> = 
-                cid.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+            let cid_bounded = Self::bound_cid(cid.clone())?;
+            let version = LatestJointKeyVersion::<T>::get()
+                .checked_add(1)
+                .ok_or(Error::<T>::KeyVersionExists)?;
 
-            JointPublicKey::<T>::put(cid_bounded);
+            JointPublicKeys::<T>::insert(version, cid_bounded.clone());
+            LatestJointKeyVersion::<T>::put(version);
+            Self::activate_joint_key(version, cid_bounded);
 
             Self::deposit_event(Event::JointPublicKeyUpdated { cid });
             Ok(())
         }
 
-        /// Mark recompute request as completed
+        /// Mark recompute request as completed, optionally attaching the
+        /// CID of a proof that the computation was performed correctly.
+        ///
+        /// `result` (user, cid, parameters, engine id, scoring) stores the aggregation
+        /// for the request's diseases, linked back to `request_id`.
+        ///
+        /// `result_cid` records the CID of the encrypted output on the request
+        /// so auditors can trace it; it defaults to the CID in `result`.
+        ///
+        /// Only `Pending` or `InProgress` requests can be completed, so the
+        /// proof and result of a finalized request are never overwritten.
         #[pallet::call_index(5)]
-        #[pallet::weight(10_000)]
+        #[pallet::weight(
+            T::WeightInfo::complete_recompute_request().saturating_add(if result.is_some() {
+                T::WeightInfo::store_aggregated_ivs(T::MaxDiseases::get())
+            } else {
+                Weight::zero()
+            })
+        )]
         pub fn complete_recompute_request(
             origin: OriginFor<T>,
             request_id: u64,
+            proof_cid: Option<Vec<u8>>,
+            result_cid: Option<Vec<u8>>,
+            result: Option<(T::AccountId, Vec<u8>, Vec<u8>, Vec<u8>, ScoringParams)>,
         ) -> DispatchResult {
             ensure_root(origin)?;
 
-            RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
+            let proof_bounded = proof_cid.clone().map(Self::bound_cid).transpose()?;
+            let result_cid =
+                result_cid.or_else(|| result.as_ref().map(|(_, cid, _, _, _)| cid.clone()));
+            let result_bounded = result_cid.clone().map(Self::bound_cid).transpose()?;
+
+            let disease_ids = RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
                 let request = request_opt.as_mut().ok_or(Error::<T>::RequestNotFound)?;
-                request.status = RequestStatus::Completed;
-                Ok::<(), Error<T>>(())
+                ensure!(
+                    matches!(request.status, RequestStatus::Pending | RequestStatus::InProgress),
+                    Error::<T>::RequestAlreadyFinalized
+                );
+                Self::set_request_status(request, RequestStatus::Completed);
+                request.proof_cid = proof_bounded;
+                request.result_cid = result_bounded;
+                Ok::<_, Error<T>>(request.disease_ids.clone())
             })?;
+            LastCompletedRequestId::<T>::put(request_id);
+
+            if let Some((user, cid, parameters, engine_id, scoring)) = result {
+                let disease_ids = disease_ids.into_iter().map(|id| id.into_inner()).collect();
+                Self::do_store_aggregated_ivs(
+                    user,
+                    cid,
+                    disease_ids,
+                    parameters,
+                    engine_id,
+                    scoring,
+                    Some(request_id),
+                )?;
+            }
 
-            Self::deposit_event(Event::RecomputeCompleted { request_id });
+            Self::deposit_event(Event::RecomputeCompleted {
+                request_id,
+                proof_cid,
+                result_cid,
+                at: Self::current_timestamp(),
+            });
             Ok(())
         }
-    }
 
-    impl<T: Config> Pallet<T> {
-        /// Get current timestamp
-        fn current_timestamp() -> u64 {
-            <frame_system::Pallet<T>>::block_number().saturated_into::<u64>()
-        }
+        /// Record the input manifest used for an in-progress recompute
+        #[pallet::call_index(6)]
+        #[pallet::weight(T::WeightInfo::set_request_manifest())]
+        pub fn set_request_manifest(
+            origin: OriginFor<T>,
+            request_id: u64,
+            manifest_cid: Vec<u8>,
+        ) -> DispatchResult {
+            T::ComputeOrigin::ensure_origin(origin)?;
 
-        /// Check if requester can decrypt for a user
-        pub fn can_decrypt(requester: &T::AccountId, _user: &T::AccountId) -> bool {
-            if let Some(policy) = CurrentDecryptionPolicy::<T>::get() {
-                // Check if policy expired
-                if let Some(expiry) = policy.expires_at {
-                    if Self::current_timestamp() > expiry {
-                        return false;
-                    }
-                }
-                
-                // Check if requester is authorized
-                policy.authorized_accounts.contains(requester)
-            } else {
-                false
-            }
+            let cid_bounded = Self::bound_cid(manifest_cid)?;
+
+            RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
+                let request = request_opt.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+                ensure!(
+                    matches!(request.status, RequestStatus::InProgress),
+                    Error::<T>::RequestNotInProgress
+                );
+                request.manifest_cid = Some(cid_bounded);
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::RequestManifestSet { request_id });
+            Ok(())
+        }
+
+        /// Re-queue a failed recompute request with exponential backoff
+        #[pallet::call_index(7)]
+        #[pallet::weight(T::WeightInfo::retry_recompute_request())]
+        pub fn retry_recompute_request(
+            origin: OriginFor<T>,
+            request_id: u64,
+        ) -> DispatchResult {
+            T::ComputeOrigin::ensure_origin(origin)?;
+
+            let (retry_count, next_retry_at) =
+                RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
+                    let request = request_opt.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+                    ensure!(
+                        matches!(request.status, RequestStatus::Failed),
+                        Error::<T>::RequestNotFailed
+                    );
+                    ensure!(request.retry_count < T::MaxRetries::get(), Error::<T>::RetryLimitReached);
+
+                    request.retry_count = request.retry_count.saturating_add(1);
+                    let next_retry_at = Self::current_timestamp()
+                        .saturating_add(Self::retry_backoff(request.retry_count));
+                    request.next_retry_at = Some(next_retry_at);
+                    request.claimed_at = None;
+                    Self::set_request_status(request, RequestStatus::Pending);
+                    Ok::<_, Error<T>>((request.retry_count, next_retry_at))
+                })?;
+
+            Self::deposit_event(Event::RecomputeRetryScheduled {
+                request_id,
+                retry_count,
+                next_retry_at,
+            });
+            Ok(())
+        }
+
+        /// Schedule an emergency committee reset after `ResetTimelock` blocks
+        #[pallet::call_index(8)]
+        #[pallet::weight(T::WeightInfo::schedule_committee_reset())]
+        pub fn schedule_committee_reset(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let available_at = Self::current_timestamp().saturating_add(T::ResetTimelock::get());
+            CommitteeResetAvailableAt::<T>::put(available_at);
+
+            Self::deposit_event(Event::CommitteeResetScheduled { available_at });
+            Ok(())
+        }
+
+        /// Wipe the committee and install `new_members` (account, name, key share id)
+        /// as decryptors once the scheduled reset's timelock has elapsed
+        ///
+        /// Weighted for removing a full committee of `MaxCommitteeSize` members.
+        #[pallet::call_index(9)]
+        #[pallet::weight(T::WeightInfo::execute_committee_reset(
+            T::MaxCommitteeSize::get(),
+            new_members.len() as u32,
+        ))]
+        pub fn execute_committee_reset(
+            origin: OriginFor<T>,
+            new_members: Vec<(T::AccountId, Vec<u8>, Vec<u8>)>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let available_at =
+                CommitteeResetAvailableAt::<T>::get().ok_or(Error::<T>::ResetNotScheduled)?;
+            ensure!(Self::current_timestamp() >= available_at, Error::<T>::ResetTimelocked);
+            ensure!(
+                new_members.len() as u32 <= T::MaxCommitteeSize::get(),
+                Error::<T>::CommitteeFull
+            );
+
+            let cleared = Committee::<T>::clear(T::MaxCommitteeSize::get(), None);
+            ensure!(cleared.maybe_cursor.is_none(), Error::<T>::CommitteeClearIncomplete);
+            CommitteeSize::<T>::put(0);
+            CommitteeResetAvailableAt::<T>::kill();
+
+            let members = new_members.len() as u32;
+            for (account, name, key_share_id) in new_members {
+                Self::do_add_committee_member(
+                    account,
+                    name,
+                    key_share_id,
+                    CommitteeRole::Decryptor,
+                )?;
+            }
+
+            Self::deposit_event(Event::CommitteeReset { members });
+            Ok(())
+        }
+
+        /// Check maintained counters against a bounded recount of storage,
+        /// emitting `InvariantViolation` for each mismatch. Read-only.
+        #[pallet::call_index(10)]
+        #[pallet::weight(T::WeightInfo::verify_invariants(T::MaxInvariantScan::get()))]
+        pub fn verify_invariants(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let cap = T::MaxInvariantScan::get() as usize;
+
+            let members = Committee::<T>::iter_keys().take(cap + 1).count();
+            if members <= cap && members as u32 != CommitteeSize::<T>::get() {
+                Self::deposit_event(Event::InvariantViolation {
+                    code: InvariantCode::CommitteeSizeMismatch,
+                });
+            }
+
+            let request_ids: Vec<u64> = RecomputeRequests::<T>::iter_keys().take(cap + 1).collect();
+            let stats: Vec<RequesterStats> =
+                RequesterStatistics::<T>::iter_values().take(cap + 1).collect();
+            if request_ids.len() <= cap {
+                let next_id = NextRequestId::<T>::get();
+                if request_ids.iter().any(|id| *id >= next_id) {
+                    Self::deposit_event(Event::InvariantViolation {
+                        code: InvariantCode::NextRequestIdBehind,
+                    });
+                }
+
+                let counted: u64 = stats.iter().map(|s| s.total_requested as u64).sum();
+                if stats.len() <= cap && counted != request_ids.len() as u64 {
+                    Self::deposit_event(Event::InvariantViolation {
+                        code: InvariantCode::RequestCountMismatch,
+                    });
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Remove committee member, refusing if the decryption policy threshold
+        /// could no longer be met by the remaining active members
+        #[pallet::call_index(11)]
+        #[pallet::weight(T::WeightInfo::remove_committee_member(T::MaxCommitteeSize::get()))]
+        pub fn remove_committee_member(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let member = Committee::<T>::get(&account).ok_or(Error::<T>::CommitteeMemberNotFound)?;
+
+            if Self::counts_toward_quorum(&member) {
+                if let Some(policy) = CurrentDecryptionPolicy::<T>::get() {
+                    let remaining = Self::quorum_member_count().saturating_sub(1);
+                    ensure!(remaining >= policy.threshold, Error::<T>::WouldBreakThreshold);
+                }
+            }
+
+            Committee::<T>::remove(&account);
+            CommitteeSize::<T>::mutate(|s| *s = s.saturating_sub(1));
+
+            Self::deposit_event(Event::CommitteeMemberRemoved {
+                account,
+                name: member.name.to_vec(),
+            });
+            Ok(())
+        }
+
+        /// Activate or suspend a committee member, keeping its key share id.
+        ///
+        /// Suspending is refused if the decryption policy threshold could no
+        /// longer be met by the remaining active members.
+        #[pallet::call_index(12)]
+        #[pallet::weight(T::WeightInfo::set_committee_member_active(T::MaxCommitteeSize::get()))]
+        pub fn set_committee_member_active(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            active: bool,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let member = Committee::<T>::get(&account).ok_or(Error::<T>::CommitteeMemberNotFound)?;
+
+            if !active && Self::counts_toward_quorum(&member) {
+                if let Some(policy) = CurrentDecryptionPolicy::<T>::get() {
+                    let remaining = Self::quorum_member_count().saturating_sub(1);
+                    ensure!(remaining >= policy.threshold, Error::<T>::WouldBreakThreshold);
+                }
+            }
+
+            Committee::<T>::mutate(&account, |member_opt| {
+                if let Some(member) = member_opt {
+                    member.is_active = active;
+                }
+            });
+
+            Self::deposit_event(Event::CommitteeMemberStatusChanged { account, active });
+            Ok(())
+        }
+
+        /// Report liveness as a committee member
+        #[pallet::call_index(13)]
+        #[pallet::weight(T::WeightInfo::committee_heartbeat())]
+        pub fn committee_heartbeat(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let at = Self::current_timestamp();
+            Committee::<T>::try_mutate(&who, |member_opt| {
+                let member = member_opt.as_mut().ok_or(Error::<T>::CommitteeMemberNotFound)?;
+                member.last_seen = at;
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::CommitteeHeartbeat { account: who, at });
+            Ok(())
+        }
+
+        /// Deactivate active members whose last heartbeat is more than `max_gap`
+        /// blocks old.
+        ///
+        /// `limit` caps the members inspected, not the members deactivated: at
+        /// most `limit` members are read in storage order and the stale ones
+        /// among them are deactivated. A stale member is skipped if deactivating
+        /// it would leave fewer members counting toward quorum than the
+        /// decryption policy threshold.
+        #[pallet::call_index(14)]
+        #[pallet::weight(T::WeightInfo::deactivate_stale_committee(
+            (*limit).min(T::MaxCommitteeSize::get()),
+            T::MaxCommitteeSize::get(),
+        ))]
+        pub fn deactivate_stale_committee(
+            origin: OriginFor<T>,
+            max_gap: u64,
+            limit: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let now = Self::current_timestamp();
+            let stale: Vec<(T::AccountId, CommitteeMember<T::AccountId>)> = Committee::<T>::iter()
+                .take(limit as usize)
+                .filter(|(_, member)| member.is_active && now.saturating_sub(member.last_seen) > max_gap)
+                .collect();
+
+            let threshold = CurrentDecryptionPolicy::<T>::get().map(|policy| policy.threshold);
+            let mut quorum = Self::quorum_member_count();
+            let mut count: u32 = 0;
+            for (account, member) in stale {
+                if Self::counts_toward_quorum(&member) {
+                    if threshold.map_or(false, |threshold| quorum.saturating_sub(1) < threshold) {
+                        continue;
+                    }
+                    quorum = quorum.saturating_sub(1);
+                }
+
+                Committee::<T>::mutate(&account, |member_opt| {
+                    if let Some(member) = member_opt {
+                        member.is_active = false;
+                    }
+                });
+                count = count.saturating_add(1);
+                Self::deposit_event(Event::CommitteeMemberStatusChanged { account, active: false });
+            }
+
+            Self::deposit_event(Event::StaleCommitteeDeactivated { count });
+            Ok(())
+        }
+
+        /// Withdraw one of the caller's own pending recompute requests
+        #[pallet::call_index(15)]
+        #[pallet::weight(T::WeightInfo::cancel_recompute_request())]
+        pub fn cancel_recompute_request(
+            origin: OriginFor<T>,
+            request_id: u64,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+            let requester = Self::requester_key(&who)?;
+
+            RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
+                let request = request_opt.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+                ensure!(request.requester == requester, Error::<T>::NotAuthorized);
+                ensure!(
+                    matches!(request.status, RequestStatus::Pending),
+                    Error::<T>::RequestNotPending
+                );
+                Self::set_request_status(request, RequestStatus::Cancelled);
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::RecomputeCancelled { request_id });
+            Ok(())
+        }
+
+        /// Allow an account to request recomputes; a non-empty allow-list
+        /// closes `request_recompute` to everyone else
+        #[pallet::call_index(16)]
+        #[pallet::weight(T::WeightInfo::add_allowed_requester())]
+        pub fn add_allowed_requester(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RequesterAllowlist::<T>::insert(&account, ());
+
+            Self::deposit_event(Event::RequesterAllowed { account });
+            Ok(())
+        }
+
+        /// Remove an account from the requester allow-list
+        #[pallet::call_index(17)]
+        #[pallet::weight(T::WeightInfo::remove_allowed_requester())]
+        pub fn remove_allowed_requester(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RequesterAllowlist::<T>::remove(&account);
+
+            Self::deposit_event(Event::RequesterDisallowed { account });
+            Ok(())
+        }
+
+        /// Mark a pending or in-progress recompute request as failed
+        #[pallet::call_index(18)]
+        #[pallet::weight(T::WeightInfo::fail_recompute_request())]
+        pub fn fail_recompute_request(
+            origin: OriginFor<T>,
+            request_id: u64,
+            reason: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let reason_bounded: BoundedVec<u8, ConstU32<256>> = 
+                reason.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
+                let request = request_opt.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+                ensure!(
+                    matches!(request.status, RequestStatus::Pending | RequestStatus::InProgress),
+                    Error::<T>::RequestAlreadyFinalized
+                );
+                Self::set_request_status(request, RequestStatus::Failed);
+                request.failure_reason = Some(reason_bounded);
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::RecomputeFailed { request_id, reason });
+            Ok(())
+        }
+
+        /// Claim a pending recompute request so no other worker picks it up
+        #[pallet::call_index(19)]
+        #[pallet::weight(T::WeightInfo::claim_recompute_request())]
+        pub fn claim_recompute_request(origin: OriginFor<T>, request_id: u64) -> DispatchResult {
+            T::ComputeOrigin::ensure_origin(origin)?;
+
+            RecomputeRequests::<T>::try_mutate(request_id, |request_opt| {
+                let request = request_opt.as_mut().ok_or(Error::<T>::RequestNotFound)?;
+                ensure!(
+                    matches!(request.status, RequestStatus::Pending),
+                    Error::<T>::AlreadyClaimed
+                );
+                Self::set_request_status(request, RequestStatus::InProgress);
+                request.claimed_at = Some(Self::current_timestamp());
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::RecomputeClaimed { request_id });
+            Ok(())
+        }
+
+        /// Set or clear the block at which a committee member's term ends
+        #[pallet::call_index(20)]
+        #[pallet::weight(T::WeightInfo::set_committee_term_end())]
+        pub fn set_committee_term_end(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            term_end: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Committee::<T>::try_mutate(&account, |member_opt| {
+                let member = member_opt.as_mut().ok_or(Error::<T>::CommitteeMemberNotFound)?;
+                member.term_end = term_end;
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::CommitteeTermSet { account, term_end });
+            Ok(())
+        }
+
+        /// Set the decryption policy for a single user, overriding the global one
+        #[pallet::call_index(21)]
+        #[pallet::weight(T::WeightInfo::set_user_decryption_policy(authorized_accounts.len() as u32))]
+        pub fn set_user_decryption_policy(
+            origin: OriginFor<T>,
+            user: T::AccountId,
+            authorized_accounts: Vec<T::AccountId>,
+            threshold: u32,
+            total_shares: u32,
+            expires_at: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let policy =
+                Self::build_decryption_policy(authorized_accounts, threshold, total_shares, expires_at)?;
+
+            UserDecryptionPolicies::<T>::insert(&user, policy);
+
+            Self::deposit_event(Event::UserDecryptionPolicyUpdated {
+                user,
+                threshold,
+                total_shares,
+            });
+            Ok(())
+        }
+
+        /// Open a threshold decryption session for a user's data
+        #[pallet::call_index(22)]
+        #[pallet::weight(T::WeightInfo::open_decryption_session(T::MaxCommitteeSize::get()))]
+        pub fn open_decryption_session(
+            origin: OriginFor<T>,
+            user: T::AccountId,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+
+            ensure!(Self::can_decrypt(&requester, &user), Error::<T>::NotAuthorized);
+
+            let session_id = NextSessionId::<T>::get();
+            NextSessionId::<T>::put(session_id.saturating_add(1));
+
+            let session = DecryptionSession {
+                session_id,
+                target_user: user.clone(),
+                requester: requester.clone(),
+                submitted_shares: BoundedVec::default(),
+                created_at: Self::current_timestamp(),
+                completed: false,
+            };
+            DecryptionSessions::<T>::insert(session_id, session);
+
+            Self::deposit_event(Event::DecryptionSessionOpened { session_id, requester, user });
+            Ok(())
+        }
+
+        /// Submit a partial decryption share (active decryptors only)
+        #[pallet::call_index(23)]
+        #[pallet::weight(T::WeightInfo::submit_decryption_share(T::MaxCommitteeSize::get()))]
+        pub fn submit_decryption_share(
+            origin: OriginFor<T>,
+            session_id: u64,
+            share: Vec<u8>,
+        ) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let member = Committee::<T>::get(&who).ok_or(Error::<T>::NotActiveMember)?;
+            ensure!(Self::counts_toward_quorum(&member), Error::<T>::NotActiveMember);
+            ensure!(member.role == CommitteeRole::Decryptor, Error::<T>::WrongRole);
+
+            let share_bounded: BoundedVec<u8, ConstU32<256>> = 
+                share.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            let threshold_reached = DecryptionSessions::<T>::try_mutate(session_id, |session_opt| {
+                let session = session_opt.as_mut().ok_or(Error::<T>::SessionNotFound)?;
+                ensure!(!session.completed, Error::<T>::SessionCompleted);
+                ensure!(
+                    !session.submitted_shares.iter().any(|(account, _)| account == &who),
+                    Error::<T>::DuplicateShare
+                );
+                session
+                    .submitted_shares
+                    .try_push((who.clone(), share_bounded))
+                    .map_err(|_| Error::<T>::CommitteeFull)?;
+
+                let threshold = Self::decryption_policy_for(&session.target_user)
+                    .map(|policy| policy.threshold)
+                    .unwrap_or(u32::MAX);
+                session.completed = session.submitted_shares.len() as u32 >= threshold;
+                Ok::<_, Error<T>>(session.completed)
+            })?;
+
+            Self::deposit_event(Event::DecryptionShareSubmitted { session_id, member: who });
+            if threshold_reached {
+                Self::deposit_event(Event::DecryptionThresholdReached { session_id });
+            }
+            Ok(())
+        }
+
+        /// Reactivate a suspended committee member, treating it as seen now so
+        /// `deactivate_stale_committee` does not immediately suspend it again
+        #[pallet::call_index(24)]
+        #[pallet::weight(T::WeightInfo::reactivate_committee_member())]
+        pub fn reactivate_committee_member(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            Committee::<T>::try_mutate(&account, |member_opt| {
+                let member = member_opt.as_mut().ok_or(Error::<T>::CommitteeMemberNotFound)?;
+                ensure!(!member.is_active, Error::<T>::NotSuspended);
+                member.is_active = true;
+                member.last_seen = Self::current_timestamp();
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::CommitteeMemberReactivated { account });
+            Ok(())
+        }
+
+        /// Replace a committee member's key share, bumping its key version and
+        /// the joint key version
+        #[pallet::call_index(25)]
+        #[pallet::weight(T::WeightInfo::rotate_key_share())]
+        pub fn rotate_key_share(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            new_key_share_id: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let key_bounded: BoundedVec<u8, ConstU32<128>> = 
+                new_key_share_id.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            let key_version = Committee::<T>::try_mutate(&account, |member_opt| {
+                let member = member_opt.as_mut().ok_or(Error::<T>::CommitteeMemberNotFound)?;
+                member.key_share_id = key_bounded;
+                member.key_version = member.key_version.saturating_add(1);
+                member.key_rotated_at = Some(Self::current_timestamp());
+                Ok::<_, Error<T>>(member.key_version)
+            })?;
+            JointKeyVersion::<T>::mutate(|v| *v = v.saturating_add(1));
+
+            Self::deposit_event(Event::KeyShareRotated { account, key_version });
+            Ok(())
+        }
+
+        /// Set a disease's recompute cadence in blocks; `None` reverts to the default
+        #[pallet::call_index(26)]
+        #[pallet::weight(T::WeightInfo::set_disease_cadence())]
+        pub fn set_disease_cadence(
+            origin: OriginFor<T>,
+            disease_id: Vec<u8>,
+            blocks: Option<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let disease_bounded: BoundedVec<u8, ConstU32<64>> = 
+                disease_id.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            match blocks {
+                Some(blocks) => DiseaseCadence::<T>::insert(disease_bounded, blocks),
+                None => DiseaseCadence::<T>::remove(disease_bounded),
+            }
+
+            Self::deposit_event(Event::DiseaseCadenceSet { disease_id, blocks });
+            Ok(())
+        }
+
+        /// Register a joint public key under `version` without making it current,
+        /// so it can be rolled out while the current key stays valid
+        #[pallet::call_index(28)]
+        #[pallet::weight(T::WeightInfo::register_joint_public_key())]
+        pub fn register_joint_public_key(
+            origin: OriginFor<T>,
+            version: u32,
+            cid: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let cid_bounded = Self::bound_cid(cid.clone())?;
+            ensure!(!JointPublicKeys::<T>::contains_key(version), Error::<T>::KeyVersionExists);
+
+            JointPublicKeys::<T>::insert(version, cid_bounded);
+            LatestJointKeyVersion::<T>::mutate(|latest| *latest = (*latest).max(version));
+
+            Self::deposit_event(Event::JointPublicKeyRegistered { version, cid });
+            Ok(())
+        }
+
+        /// Make a registered joint public key version the current one
+        #[pallet::call_index(29)]
+        #[pallet::weight(T::WeightInfo::set_current_joint_key_version())]
+        pub fn set_current_joint_key_version(
+            origin: OriginFor<T>,
+            version: u32,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let cid = JointPublicKeys::<T>::get(version).ok_or(Error::<T>::UnknownKeyVersion)?;
+            Self::activate_joint_key(version, cid);
+
+            Self::deposit_event(Event::CurrentJointKeyVersionSet { version });
+            Ok(())
+        }
+
+        /// Record that the caller is accessing a user's data, appending to the
+        /// audit log when the applicable policy has auditing enabled
+        #[pallet::call_index(30)]
+        #[pallet::weight(T::WeightInfo::record_decryption_access(T::MaxCommitteeSize::get()))]
+        pub fn record_decryption_access(
+            origin: OriginFor<T>,
+            user: T::AccountId,
+        ) -> DispatchResult {
+            let requester = ensure_signed(origin)?;
+
+            ensure!(Self::can_decrypt(&requester, &user), Error::<T>::NotAuthorized);
+
+            if Self::decryption_policy_for(&user).map_or(false, |policy| policy.audit_enabled) {
+                Self::append_audit_entry(requester.clone(), user.clone());
+            }
+
+            Self::deposit_event(Event::DecryptionAuthorized { requester, user });
+            Ok(())
+        }
+
+        /// Step down from the committee, refusing if the decryption policy
+        /// threshold could no longer be met by the remaining active members
+        #[pallet::call_index(27)]
+        #[pallet::weight(T::WeightInfo::resign_committee(T::MaxCommitteeSize::get()))]
+        pub fn resign_committee(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let member = Committee::<T>::get(&who).ok_or(Error::<T>::CommitteeMemberNotFound)?;
+            ensure!(member.is_active, Error::<T>::NotActiveMember);
+
+            if Self::counts_toward_quorum(&member) {
+                if let Some(policy) = CurrentDecryptionPolicy::<T>::get() {
+                    let remaining = Self::quorum_member_count().saturating_sub(1);
+                    ensure!(remaining >= policy.threshold, Error::<T>::WouldBreakThreshold);
+                }
+            }
+
+            Committee::<T>::mutate(&who, |member_opt| {
+                if let Some(member) = member_opt {
+                    member.is_active = false;
+                }
+            });
+
+            Self::deposit_event(Event::CommitteeMemberResigned { account: who });
+            Ok(())
+        }
+
+        /// Mark pending or in-progress requests completed in bulk, e.g. when
+        /// reconciling after a batch compute job.
+        ///
+        /// At most `MaxCompletionBatch` ids are processed; unknown ids and
+        /// requests already in a final status are skipped.
+        #[pallet::call_index(31)]
+        #[pallet::weight(T::WeightInfo::complete_recompute_requests_batch(
+            (request_ids.len() as u32).min(T::MaxCompletionBatch::get())
+        ))]
+        pub fn complete_recompute_requests_batch(
+            origin: OriginFor<T>,
+            request_ids: Vec<u64>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let mut count: u32 = 0;
+            let mut last_completed = None;
+            for request_id in request_ids.into_iter().take(T::MaxCompletionBatch::get() as usize) {
+                RecomputeRequests::<T>::mutate(request_id, |request_opt| {
+                    let Some(request) = request_opt.as_mut() else {
+                        return;
+                    };
+                    if matches!(request.status, RequestStatus::Pending | RequestStatus::InProgress) {
+                        Self::set_request_status(request, RequestStatus::Completed);
+                        count = count.saturating_add(1);
+                        last_completed = Some(request_id);
+                    }
+                });
+            }
+            if let Some(request_id) = last_completed {
+                LastCompletedRequestId::<T>::put(request_id);
+            }
+
+            Self::deposit_event(Event::RecomputeBatchCompleted { count });
+            Ok(())
+        }
+
+        /// Rename a committee member; the key share is left untouched
+        #[pallet::call_index(32)]
+        #[pallet::weight(T::WeightInfo::update_committee_member_name())]
+        pub fn update_committee_member_name(
+            origin: OriginFor<T>,
+            account: T::AccountId,
+            name: Vec<u8>,
+        ) -> DispatchResult {
+            ensure_root(origin)?;
+
+            let name_bounded: BoundedVec<u8, ConstU32<64>> =
+                name.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            Committee::<T>::try_mutate(&account, |member_opt| {
+                let member = member_opt.as_mut().ok_or(Error::<T>::CommitteeMemberNotFound)?;
+                member.name = name_bounded;
+                Ok::<(), Error<T>>(())
+            })?;
+
+            Self::deposit_event(Event::CommitteeMemberRenamed { account, name });
+            Ok(())
+        }
+
+        /// Stop accepting new recompute requests; queued requests are unaffected
+        #[pallet::call_index(33)]
+        #[pallet::weight(T::WeightInfo::pause_recompute())]
+        pub fn pause_recompute(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RecomputePaused::<T>::put(true);
+
+            Self::deposit_event(Event::RecomputePauseToggled { paused: true });
+            Ok(())
+        }
+
+        /// Accept new recompute requests again
+        #[pallet::call_index(34)]
+        #[pallet::weight(T::WeightInfo::unpause_recompute())]
+        pub fn unpause_recompute(origin: OriginFor<T>) -> DispatchResult {
+            ensure_root(origin)?;
+
+            RecomputePaused::<T>::put(false);
+
+            Self::deposit_event(Event::RecomputePauseToggled { paused: false });
+            Ok(())
+        }
+
+        /// Flag the joint key as suspected compromised (committee members only).
+        ///
+        /// Only flags from members counting toward quorum are counted;
+        /// `KeyCompromiseDeclared` is emitted once they form a strict majority
+        /// of `quorum_member_count`.
+        #[pallet::call_index(35)]
+        #[pallet::weight(T::WeightInfo::flag_key_compromise(T::MaxCommitteeSize::get()))]
+        pub fn flag_key_compromise(origin: OriginFor<T>) -> DispatchResult {
+            let who = ensure_signed(origin)?;
+
+            let member = Committee::<T>::get(&who).ok_or(Error::<T>::CommitteeMemberNotFound)?;
+            ensure!(!KeyCompromiseFlags::<T>::contains_key(&who), Error::<T>::AlreadyFlagged);
+
+            KeyCompromiseFlags::<T>::insert(&who, Self::current_timestamp());
+            Self::deposit_event(Event::KeyCompromiseFlagged { member: who });
+
+            // Declared only by the flag that reaches the majority, not by later ones
+            let (flags, quorum) = Self::key_compromise_flag_count();
+            if Self::counts_toward_quorum(&member) && flags == quorum / 2 + 1 {
+                Self::deposit_event(Event::KeyCompromiseDeclared { flags });
+            }
+            Ok(())
+        }
+    }
+
+    impl<T: Config> Pallet<T> {
+        /// Get current timestamp
+        fn current_timestamp() -> u64 {
+            <frame_system::Pallet<T>>::block_number().saturated_into::<u64>()
+        }
+
+        /// Check a CID is well formed and matches the configured `AllowedCidPrefixes`
+        pub fn validate_cid(bytes: &[u8]) -> bool {
+            ivs_primitives::is_well_formed_cid(bytes)
+                && T::AllowedCidPrefixes::get()
+                    .iter()
+                    .any(|prefix| bytes.starts_with(prefix))
+        }
+
+        /// Validate a CID and convert it into its bounded storage form
+        fn bound_cid(cid: Vec<u8>) -> Result<BoundedVec<u8, ConstU32<128>>, Error<T>> {
+            ensure!(Self::validate_cid(&cid), Error::<T>::InvalidCID);
+            cid.try_into().map_err(|_| Error::<T>::InvalidCID)
+        }
+
+        /// Count one more history entry referencing `cid`
+        fn retain_cid(cid: &BoundedVec<u8, ConstU32<128>>) {
+            CidRefCount::<T>::mutate(cid, |count| *count = count.saturating_add(1));
+        }
+
+        /// Count one fewer history entry referencing `cid`, dropping it at zero
+        fn release_cid(cid: &BoundedVec<u8, ConstU32<128>>) {
+            CidRefCount::<T>::mutate_exists(cid, |count| {
+                *count = count.map(|count| count.saturating_sub(1)).filter(|count| *count > 0);
+            });
+        }
+
+        /// Number of aggregated IVS history entries referencing `cid`
+        pub fn get_cid_refcount(cid: Vec<u8>) -> u32 {
+            BoundedVec::<u8, ConstU32<128>>::try_from(cid)
+                .map_or(0, |cid| CidRefCount::<T>::get(cid))
+        }
+
+        /// Bound a disease list, enforcing the configured `MaxDiseases` cap
+        fn bound_disease_ids(
+            disease_ids: &[Vec<u8>],
+        ) -> Result<BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<16>>, Error<T>> {
+            ensure!(
+                disease_ids.len() as u32 <= T::MaxDiseases::get(),
+                Error::<T>::TooManyDiseases
+            );
+
+            disease_ids.iter()
+                .map(|d| d.clone().try_into().map_err(|_| Error::<T>::TooManyDiseases))
+                .collect::<Result<Vec<_>, _>>()?
+                .try_into().map_err(|_| Error::<T>::TooManyDiseases)
+        }
+
+        /// Append a decryption audit entry, dropping the oldest one beyond
+        /// `MaxAuditLogEntries`
+        fn append_audit_entry(requester: T::AccountId, user: T::AccountId) {
+            let index = NextAuditIndex::<T>::get();
+            DecryptionAuditLog::<T>::insert(index, (requester, user, Self::current_timestamp()));
+            NextAuditIndex::<T>::put(index.saturating_add(1));
+
+            let max = T::MaxAuditLogEntries::get() as u64;
+            if index >= max {
+                DecryptionAuditLog::<T>::remove(index - max);
+            }
+        }
+
+        /// Point `CurrentJointKeyVersion` at a registered key, recording the
+        /// rotation in `KeyHistory`
+        fn activate_joint_key(version: u32, cid: BoundedVec<u8, ConstU32<128>>) {
+            KeyHistory::<T>::mutate(|history| {
+                if history.is_full() {
+                    history.remove(0);
+                }
+                // Cannot fail: an entry was evicted above if the history was full
+                let _ = history.try_push((cid, Self::current_timestamp()));
+            });
+            CurrentJointKeyVersion::<T>::put(version);
+            JointKeyVersion::<T>::mutate(|v| *v = v.saturating_add(1));
+        }
+
+        /// Validate an aggregation and make it the user's current record
+        fn do_store_aggregated_ivs(
+            user: T::AccountId,
+            cid: Vec<u8>,
+            disease_ids: Vec<Vec<u8>>,
+            parameters: Vec<u8>,
+            engine_id: Vec<u8>,
+            scoring: ScoringParams,
+            request_id: Option<u64>,
+        ) -> DispatchResult {
+            let cid_bounded = Self::bound_cid(cid.clone())?;
+
+            ensure!(!engine_id.is_empty(), Error::<T>::InvalidParameters);
+            let engine_bounded: BoundedVec<u8, ConstU32<32>> = 
+                engine_id.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            let diseases_bounded = Self::bound_disease_ids(&disease_ids)?;
+
+            let params_bounded: BoundedVec<u8, ConstU32<256>> = 
+                parameters.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            let aggregated = AggregatedIVS {
+                cid: cid_bounded,
+                disease_ids: diseases_bounded,
+                computed_at: Self::current_timestamp(),
+                parameters: params_bounded,
+                scoring,
+                engine_id: engine_bounded,
+                request_id,
+            };
+
+            Self::retain_cid(&aggregated.cid);
+            AggregatedIVSHistory::<T>::mutate(&user, |history| {
+                if history.is_full() {
+                    let evicted = history.remove(0);
+                    Self::release_cid(&evicted.cid);
+                }
+                // Cannot fail: an entry was evicted above if the history was full
+                let _ = history.try_push(aggregated.clone());
+            });
+            AggregatedIVSScores::<T>::insert(&user, aggregated);
+
+            Self::deposit_event(Event::AggregatedIVSStored {
+                user,
+                cid,
+                diseases: disease_ids,
+                engine_id,
+                at: Self::current_timestamp(),
+            });
+            Ok(())
+        }
+
+        /// Validate and insert a new committee member
+        fn do_add_committee_member(
+            account: T::AccountId,
+            name: Vec<u8>,
+            key_share_id: Vec<u8>,
+            role: CommitteeRole,
+        ) -> DispatchResult {
+            ensure!(!Committee::<T>::contains_key(&account), Error::<T>::CommitteeMemberExists);
+            
+            let size = CommitteeSize::<T>::get();
+            ensure!(size < T::MaxCommitteeSize::get(), Error::<T>::CommitteeFull);
+
+            let name_bounded: BoundedVec<u8, ConstU32<64>> = 
+                name.clone().try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+            let key_bounded: BoundedVec<u8, ConstU32<128>> = 
+                key_share_id.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            let joined_at = Self::current_timestamp();
+            let member = CommitteeMember {
+                account: account.clone(),
+                name: name_bounded,
+                key_share_id: key_bounded,
+                is_active: true,
+                joined_at,
+                active_after: joined_at.saturating_add(T::CommitteeGracePeriod::get()),
+                last_seen: joined_at,
+                term_end: None,
+                key_version: 0,
+                key_rotated_at: None,
+                role,
+            };
+
+            Committee::<T>::insert(&account, member);
+            CommitteeSize::<T>::mutate(|s| *s = s.saturating_add(1));
+
+            Self::deposit_event(Event::CommitteeMemberAdded { account, name });
+            Ok(())
+        }
+
+        /// Check if requester can decrypt for a user
+        pub fn can_decrypt(requester: &T::AccountId, user: &T::AccountId) -> bool {
+            Self::decryption_check(requester, user) == DecryptionDecision::Allowed
+        }
+
+        /// Validate policy parameters against the committee and build the policy
+        fn build_decryption_policy(
+            authorized_accounts: Vec<T::AccountId>,
+            threshold: u32,
+            total_shares: u32,
+            expires_at: Option<u64>,
+        ) -> Result<DecryptionPolicy<T::AccountId>, Error<T>> {
+            ensure!(
+                threshold >= 1
+                    && threshold <= total_shares
+                    && total_shares <= T::MaxCommitteeSize::get(),
+                Error::<T>::InvalidParameters
+            );
+            ensure!(
+                authorized_accounts.iter().all(Committee::<T>::contains_key),
+                Error::<T>::InvalidParameters
+            );
+
+            let size = CommitteeSize::<T>::get();
+            ensure!(
+                size >= total_shares && size >= T::MinCommitteeForPolicy::get(),
+                Error::<T>::CommitteeTooSmall
+            );
+
+            let accounts_bounded: BoundedVec<T::AccountId, ConstU32<16>> = 
+                authorized_accounts.try_into().map_err(|_| Error::<T>::InvalidParameters)?;
+
+            Ok(DecryptionPolicy {
+                authorized_accounts: accounts_bounded,
+                threshold,
+                total_shares,
+                expires_at,
+                audit_enabled: true,
+            })
+        }
+
+        /// Evaluate `can_decrypt` for each user, at most `MaxDecryptionBatch` of them
+        pub fn can_decrypt_batch(
+            requester: &T::AccountId,
+            users: Vec<T::AccountId>,
+        ) -> Vec<(T::AccountId, bool)> {
+            users
+                .into_iter()
+                .take(T::MaxDecryptionBatch::get() as usize)
+                .map(|user| {
+                    let allowed = Self::can_decrypt(requester, &user);
+                    (user, allowed)
+                })
+                .collect()
+        }
+
+        /// Policy governing decryption of a user's data: their own, else the global one
+        pub fn decryption_policy_for(user: &T::AccountId) -> Option<DecryptionPolicy<T::AccountId>> {
+            UserDecryptionPolicies::<T>::get(user).or_else(CurrentDecryptionPolicy::<T>::get)
+        }
+
+        /// Check if requester can decrypt for a user, with the reason when denied.
+        ///
+        /// The user's own policy applies if set, otherwise the global policy.
+        pub fn decryption_check(requester: &T::AccountId, user: &T::AccountId) -> DecryptionDecision {
+            let Some(policy) = Self::decryption_policy_for(user) else {
+                return DecryptionDecision::NoPolicy;
+            };
+
+            // Check if policy expired
+            if let Some(expiry) = policy.expires_at {
+                if Self::current_timestamp() > expiry {
+                    return DecryptionDecision::Expired;
+                }
+            }
+
+            // Only active members can contribute shares toward the threshold
+            if Self::quorum_member_count() < policy.threshold {
+                return DecryptionDecision::NoQuorum;
+            }
+
+            // Check if requester is authorized
+            if policy.authorized_accounts.contains(requester) {
+                DecryptionDecision::Allowed
+            } else {
+                DecryptionDecision::NotAuthorized
+            }
+        }
+
+        /// Whether a member is active and past its grace period
+        pub fn counts_toward_quorum(member: &CommitteeMember<T::AccountId>) -> bool {
+            member.is_active && Self::current_timestamp() >= member.active_after
+        }
+
+        /// Number of committee members currently counting toward quorum
+        pub fn quorum_member_count() -> u32 {
+            Committee::<T>::iter_values()
+                .filter(|member| Self::counts_toward_quorum(member))
+                .count() as u32
+        }
+
+        /// Key-compromise flags from members counting toward quorum, and the
+        /// number of such members
+        pub fn key_compromise_flag_count() -> (u32, u32) {
+            let mut flags: u32 = 0;
+            let mut quorum: u32 = 0;
+            for (account, member) in Committee::<T>::iter() {
+                if Self::counts_toward_quorum(&member) {
+                    quorum = quorum.saturating_add(1);
+                    if KeyCompromiseFlags::<T>::contains_key(&account) {
+                        flags = flags.saturating_add(1);
+                    }
+                }
+            }
+            (flags, quorum)
+        }
+
+        /// Whether enough members count toward quorum to meet the global
+        /// policy's threshold (false if no policy is set)
+        pub fn has_decryption_quorum() -> bool {
+            CurrentDecryptionPolicy::<T>::get()
+                .map_or(false, |policy| Self::quorum_member_count() >= policy.threshold)
+        }
+
+        /// Get the input manifest CID recorded for a request
+        pub fn request_manifest(request_id: u64) -> Option<Vec<u8>> {
+            RecomputeRequests::<T>::get(request_id)
+                .and_then(|request| request.manifest_cid)
+                .map(|cid| cid.to_vec())
+        }
+
+        /// Requester identity as stored on recompute requests
+        fn requester_key(who: &T::AccountId) -> Result<BoundedVec<u8, ConstU32<128>>, Error<T>> {
+            format!("{:?}", who).as_bytes().to_vec()
+                .try_into().map_err(|_| Error::<T>::InvalidParameters)
+        }
+
+        /// Count a new request against the account's rate limit.
+        ///
+        /// Approximates a rolling window of `RequestWindowBlocks`: requests in
+        /// the current fixed window count in full, and the previous window's
+        /// count is weighted by the share of it still inside the rolling window.
+        fn note_request_rate(who: &T::AccountId) -> DispatchResult {
+            let now = Self::current_timestamp();
+            let window = T::RequestWindowBlocks::get().max(1);
+            RequestRateLimits::<T>::try_mutate(who, |(window_start, count, previous)| {
+                let elapsed = now.saturating_sub(*window_start);
+                if elapsed >= window {
+                    // Only the window right before the current one is still in range
+                    *previous = if elapsed < window.saturating_mul(2) {
+                        *count
+                    } else {
+                        0
+                    };
+                    *window_start = now.saturating_sub(elapsed % window);
+                    *count = 0;
+                }
+
+                let into_window = now.saturating_sub(*window_start);
+                let carried =
+                    (*previous as u64).saturating_mul(window.saturating_sub(into_window)) / window;
+                ensure!(
+                    carried.saturating_add(*count as u64) < T::MaxRequestsPerWindow::get() as u64,
+                    Error::<T>::RateLimited
+                );
+                *count = count.saturating_add(1);
+                Ok(())
+            })
+        }
+
+        /// Update a request's status, keeping the requester's statistics and
+        /// `PendingRequestCount` in step
+        fn set_request_status(request: &mut RecomputeRequest, status: RequestStatus) {
+            RequesterStatistics::<T>::mutate(&request.requester, |stats| {
+                stats.note_transition(&request.status, &status)
+            });
+            match (&request.status, &status) {
+                (RequestStatus::Pending, RequestStatus::Pending) => {},
+                (RequestStatus::Pending, _) => {
+                    PendingRequestCount::<T>::mutate(|count| *count = count.saturating_sub(1))
+                },
+                (_, RequestStatus::Pending) => {
+                    PendingRequestCount::<T>::mutate(|count| *count = count.saturating_add(1))
+                },
+                _ => {},
+            }
+            request.status = status;
+        }
+
+        /// Fail `Pending`/`InProgress` requests older than `RequestExpiryBlocks`.
+        ///
+        /// Walks request ids from `ExpiryCursor`, inspecting at most
+        /// `MaxExpiryScan` per call and stopping at the first unfinished
+        /// request that is still fresh, since every later id is younger.
+        /// Requests re-queued by `retry_recompute_request` after the cursor
+        /// passed them are bounded by `MaxRetries` instead.
+        fn expire_stale_requests() -> Weight {
+            let db = T::DbWeight::get();
+            let expiry = T::RequestExpiryBlocks::get();
+            if expiry == 0 {
+                return Weight::zero();
+            }
+
+            let now = Self::current_timestamp();
+            let next_id = NextRequestId::<T>::get();
+            let start = ExpiryCursor::<T>::get();
+            let mut cursor = start;
+            let mut scanned: u64 = 0;
+            let mut expired: u64 = 0;
+            while cursor < next_id && scanned < T::MaxExpiryScan::get() as u64 {
+                scanned += 1;
+                let Some(mut request) = RecomputeRequests::<T>::get(cursor) else {
+                    cursor = cursor.saturating_add(1);
+                    continue;
+                };
+                if matches!(request.status, RequestStatus::Pending | RequestStatus::InProgress) {
+                    if now.saturating_sub(request.requested_at) < expiry {
+                        break;
+                    }
+                    Self::set_request_status(&mut request, RequestStatus::Failed);
+                    request.failure_reason = Some(
+                        b"expired".to_vec().try_into().expect("reason fits in 256 bytes"),
+                    );
+                    RecomputeRequests::<T>::insert(cursor, request);
+                    expired += 1;
+                    Self::deposit_event(Event::RecomputeExpired { request_id: cursor });
+                }
+                cursor = cursor.saturating_add(1);
+            }
+
+            let mut writes = expired.saturating_mul(3);
+            if cursor != start {
+                ExpiryCursor::<T>::put(cursor);
+                writes = writes.saturating_add(1);
+            }
+
+            let reads = scanned.saturating_add(expired.saturating_mul(2)).saturating_add(2);
+            db.reads_writes(reads, writes)
+        }
+
+        /// CID of the current joint public key (empty if none is registered)
+        pub fn joint_public_key() -> BoundedVec<u8, ConstU32<128>> {
+            JointPublicKeys::<T>::get(CurrentJointKeyVersion::<T>::get()).unwrap_or_default()
+        }
+
+        /// Everything recorded about a decryption session, or `None` for an unknown id.
+        ///
+        /// `shares_remaining` is measured against the threshold of the target
+        /// user's policy, as when shares are submitted.
+        pub fn decryption_request(session_id: u64) -> Option<DecryptionRequestView<T::AccountId>> {
+            let session = DecryptionSessions::<T>::get(session_id)?;
+            let threshold = Self::decryption_policy_for(&session.target_user)
+                .map(|policy| policy.threshold)
+                .unwrap_or(u32::MAX);
+            let contributors: Vec<T::AccountId> =
+                session.submitted_shares.into_iter().map(|(account, _)| account).collect();
+
+            Some(DecryptionRequestView {
+                session_id: session.session_id,
+                requester: session.requester,
+                target_user: session.target_user,
+                created_at: session.created_at,
+                shares_remaining: threshold.saturating_sub(contributors.len() as u32),
+                contributors,
+                completed: session.completed,
+            })
+        }
+
+        /// Recompute request that produced a user's current aggregation
+        pub fn request_for_aggregation(user: &T::AccountId) -> Option<u64> {
+            AggregatedIVSScores::<T>::get(user).and_then(|aggregated| aggregated.request_id)
+        }
+
+        /// Id the next `request_recompute` will be assigned (read-only)
+        pub fn next_request_id_peek() -> u64 {
+            NextRequestId::<T>::get()
+        }
+
+        /// Get recompute statistics for a requester
+        pub fn requester_stats(requester: Vec<u8>) -> RequesterStats {
+            BoundedVec::<u8, ConstU32<128>>::try_from(requester)
+                .map(RequesterStatistics::<T>::get)
+                .unwrap_or_default()
+        }
+
+        /// Backoff delay before the given retry: base * 2^(retry_count - 1)
+        pub fn retry_backoff(retry_count: u32) -> u64 {
+            T::RetryBackoffBase::get()
+                .saturating_mul(2u64.saturating_pow(retry_count.saturating_sub(1)))
+        }
+
+        /// Whether a pending request may be picked up at the current block
+        fn is_due(request: &RecomputeRequest) -> bool {
+            request
+                .next_retry_at
+                .map_or(true, |at| Self::current_timestamp() >= at)
+        }
+
+        /// Whether the user's aggregated IVS was computed after `since`
+        /// (false if no aggregation exists)
+        pub fn aggregation_changed_since(user: &T::AccountId, since: u64) -> bool {
+            AggregatedIVSScores::<T>::get(user).map_or(false, |agg| agg.computed_at > since)
+        }
+
+        /// Recompute cadence of a disease, falling back to `DefaultRecomputeCadence`
+        pub fn cadence_of(disease_id: Vec<u8>) -> u64 {
+            BoundedVec::<u8, ConstU32<64>>::try_from(disease_id)
+                .ok()
+                .and_then(DiseaseCadence::<T>::get)
+                .unwrap_or_else(T::DefaultRecomputeCadence::get)
+        }
+
+        /// Whether a user's aggregation is older than the shortest cadence of
+        /// the diseases it includes. Users with no aggregation are stale.
+        pub fn aggregation_is_stale(user: &T::AccountId) -> bool {
+            let Some(aggregated) = AggregatedIVSScores::<T>::get(user) else {
+                return true;
+            };
+            let cadence = aggregated
+                .disease_ids
+                .iter()
+                .map(|disease_id| Self::cadence_of(disease_id.to_vec()))
+                .min()
+                .unwrap_or_else(T::DefaultRecomputeCadence::get);
+            Self::current_timestamp().saturating_sub(aggregated.computed_at) >= cadence
+        }
+
+        /// Users whose aggregation was produced by `engine_id`.
+        ///
+        /// Inspects at most `limit` records after the `start` cursor and returns
+        /// the matches plus the cursor for the next page (`None` when done).
+        pub fn aggregations_by_engine(
+            engine_id: Vec<u8>,
+            limit: u32,
+            start: Option<T::AccountId>,
+        ) -> (Vec<T::AccountId>, Option<T::AccountId>) {
+            let mut iter = match start {
+                Some(account) => AggregatedIVSScores::<T>::iter_from(
+                    AggregatedIVSScores::<T>::hashed_key_for(account),
+                ),
+                None => AggregatedIVSScores::<T>::iter(),
+            };
+
+            let mut users = Vec::new();
+            let mut last = None;
+            for (user, aggregated) in iter.by_ref().take(limit as usize) {
+                if aggregated.engine_id.as_slice() == engine_id.as_slice() {
+                    users.push(user.clone());
+                }
+                last = Some(user);
+            }
+
+            let next = if iter.next().is_some() { last } else { None };
+            (users, next)
+        }
+
+        /// All retained aggregated IVS results for a user, oldest first
+        pub fn get_aggregated_ivs_history(user: &T::AccountId) -> Vec<AggregatedIVS> {
+            AggregatedIVSHistory::<T>::get(user).into_inner()
+        }
+
+        /// Page through aggregated IVS records.
+        ///
+        /// Returns up to `limit` records after the `start_key` cursor plus the
+        /// cursor for the next page (`None` when done).
+        pub fn get_aggregated_ivs_page(
+            start_key: Option<T::AccountId>,
+            limit: u32,
+        ) -> (Vec<(T::AccountId, AggregatedIVS)>, Option<T::AccountId>) {
+            let mut iter = match start_key {
+                Some(account) => AggregatedIVSScores::<T>::iter_from(
+                    AggregatedIVSScores::<T>::hashed_key_for(account),
+                ),
+                None => AggregatedIVSScores::<T>::iter(),
+            };
+
+            let page: Vec<_> = iter.by_ref().take(limit as usize).collect();
+            let next = if iter.next().is_some() {
+                page.last().map(|(user, _)| user.clone())
+            } else {
+                None
+            };
+            (page, next)
         }
 
         /// Get all committee members
@@ -422,12 +2393,77 @@ pub mod pallet {
             Committee::<T>::iter_keys().collect()
         }
 
-        /// Get pending recompute requests
+        /// Get active committee members
+        pub fn get_active_committee_members() -> Vec<T::AccountId> {
+            Committee::<T>::iter()
+                .filter(|(_, member)| member.is_active)
+                .map(|(account, _)| account)
+                .collect()
+        }
+
+        /// Committee members whose term ends within `now..=now + within`,
+        /// soonest first, at most `limit` of them.
+        ///
+        /// Scans at most `MaxCommitteeSize` members.
+        pub fn expiring_committee_members(
+            within: u64,
+            now: u64,
+            limit: u32,
+        ) -> Vec<(T::AccountId, u64)> {
+            let horizon = now.saturating_add(within);
+            let mut expiring: Vec<(T::AccountId, u64)> = Committee::<T>::iter()
+                .take(T::MaxCommitteeSize::get() as usize)
+                .filter_map(|(account, member)| {
+                    member
+                        .term_end
+                        .filter(|end| (now..=horizon).contains(end))
+                        .map(|end| (account, end))
+                })
+                .collect();
+
+            expiring.sort_by_key(|(_, end)| *end);
+            expiring.truncate(limit as usize);
+            expiring
+        }
+
+        /// Number of recompute requests in `Pending` status, including ones
+        /// still backing off before a retry
+        pub fn get_pending_request_count() -> u32 {
+            PendingRequestCount::<T>::get()
+        }
+
+        /// Get pending recompute requests that are due for pickup
         pub fn get_pending_requests() -> Vec<u64> {
             RecomputeRequests::<T>::iter()
-                .filter(|(_, req)| matches!(req.status, RequestStatus::Pending))
+                .filter(|(_, req)| matches!(req.status, RequestStatus::Pending) && Self::is_due(req))
+                .map(|(id, _)| id)
+                .collect()
+        }
+
+        /// Get recompute requests currently in `status`
+        pub fn get_requests_by_status(status: RequestStatus) -> Vec<u64> {
+            RecomputeRequests::<T>::iter()
+                .filter(|(_, req)| req.status == status)
+                .map(|(id, _)| id)
+                .collect()
+        }
+
+        /// Get every recompute request filed by `requester`, in any status
+        pub fn get_requests_by_requester(requester: Vec<u8>) -> Vec<u64> {
+            RecomputeRequests::<T>::iter()
+                .filter(|(_, req)| req.requester.as_slice() == requester.as_slice())
                 .map(|(id, _)| id)
                 .collect()
         }
     }
+
+    impl<T: Config> ivs_primitives::ParticipationMetrics for Pallet<T> {
+        fn committee_size() -> u32 {
+            CommitteeSize::<T>::get()
+        }
+
+        fn pending_request_count() -> u32 {
+            PendingRequestCount::<T>::get()
+        }
+    }
 }
@@ -0,0 +1,384 @@
+//! Storage migrations for the ivs-aggregator pallet.
+
+use super::*;
+use frame_support::{
+    migrations::VersionedMigration,
+    pallet_prelude::*,
+    storage_alias,
+    traits::UncheckedOnRuntimeUpgrade,
+};
+use sp_runtime::SaturatedConversion;
+use sp_std::{collections::btree_map::BTreeMap, vec, vec::Vec};
+
+/// Records as laid out by the original release, before storage version 1.
+mod v0 {
+    use super::*;
+
+    #[derive(Encode, Decode)]
+    pub struct AggregatedIVS {
+        pub cid: BoundedVec<u8, ConstU32<128>>,
+        pub disease_ids: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<16>>,
+        pub computed_at: u64,
+        pub parameters: BoundedVec<u8, ConstU32<256>>,
+    }
+
+    impl AggregatedIVS {
+        /// Pre-v3 layout, tagged with [`v1::LEGACY_ENGINE_ID`] and no request
+        pub fn upgrade(self) -> v2_aggregated::AggregatedIVS {
+            v2_aggregated::AggregatedIVS {
+                cid: self.cid,
+                disease_ids: self.disease_ids,
+                computed_at: self.computed_at,
+                parameters: self.parameters,
+                engine_id: BoundedVec::truncate_from(v1::LEGACY_ENGINE_ID.to_vec()),
+                request_id: None,
+            }
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    pub struct RecomputeRequest {
+        pub request_id: u64,
+        pub requester: BoundedVec<u8, ConstU32<128>>,
+        pub target_user: Option<BoundedVec<u8, ConstU32<128>>>,
+        pub disease_ids: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<16>>,
+        pub requested_at: u64,
+        pub status: RequestStatus,
+    }
+
+    impl RecomputeRequest {
+        /// Current layout, never retried, claimed or annotated
+        pub fn upgrade(self) -> super::RecomputeRequest {
+            super::RecomputeRequest {
+                request_id: self.request_id,
+                requester: self.requester,
+                target_user: self.target_user,
+                disease_ids: self.disease_ids,
+                requested_at: self.requested_at,
+                status: self.status,
+                manifest_cid: None,
+                retry_count: 0,
+                next_retry_at: None,
+                proof_cid: None,
+                failure_reason: None,
+                claimed_at: None,
+                algorithm_params: None,
+                result_cid: None,
+            }
+        }
+    }
+
+    #[derive(Encode, Decode)]
+    pub struct CommitteeMember<AccountId> {
+        pub account: AccountId,
+        pub name: BoundedVec<u8, ConstU32<64>>,
+        pub key_share_id: BoundedVec<u8, ConstU32<128>>,
+        pub is_active: bool,
+        pub joined_at: u64,
+    }
+
+    impl<AccountId> CommitteeMember<AccountId> {
+        /// Current layout: an established decryptor with no term limit or key
+        /// rotations, counting toward quorum from its join block and treated
+        /// as seen at `now` so it is not immediately stale
+        pub fn upgrade(self, now: u64) -> super::CommitteeMember<AccountId> {
+            super::CommitteeMember {
+                account: self.account,
+                name: self.name,
+                key_share_id: self.key_share_id,
+                is_active: self.is_active,
+                joined_at: self.joined_at,
+                active_after: self.joined_at,
+                last_seen: now,
+                term_end: None,
+                key_version: 0,
+                key_rotated_at: None,
+                role: CommitteeRole::Decryptor,
+            }
+        }
+    }
+}
+
+/// Aggregations as laid out before storage version 3, without scoring parameters.
+mod v2_aggregated {
+    use super::*;
+
+    #[derive(Clone, Encode, Decode)]
+    pub struct AggregatedIVS {
+        pub cid: BoundedVec<u8, ConstU32<128>>,
+        pub disease_ids: BoundedVec<BoundedVec<u8, ConstU32<64>>, ConstU32<16>>,
+        pub computed_at: u64,
+        pub parameters: BoundedVec<u8, ConstU32<256>>,
+        pub engine_id: BoundedVec<u8, ConstU32<32>>,
+        pub request_id: Option<u64>,
+    }
+
+    impl AggregatedIVS {
+        /// Current layout, with zeroed scoring parameters
+        pub fn upgrade(self) -> super::AggregatedIVS {
+            super::AggregatedIVS {
+                cid: self.cid,
+                disease_ids: self.disease_ids,
+                computed_at: self.computed_at,
+                parameters: self.parameters,
+                scoring: ScoringParams::default(),
+                engine_id: self.engine_id,
+                request_id: self.request_id,
+            }
+        }
+    }
+
+    #[storage_alias]
+    pub type AggregatedIVSScores<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        AggregatedIVS,
+        OptionQuery,
+    >;
+
+    #[storage_alias]
+    pub type AggregatedIVSHistory<T: Config> = StorageMap<
+        Pallet<T>,
+        Blake2_128Concat,
+        <T as frame_system::Config>::AccountId,
+        BoundedVec<AggregatedIVS, ConstU32<32>>,
+        ValueQuery,
+    >;
+}
+
+/// Migration from the original release: baseline records are translated to
+/// the layouts later steps expect, and the single joint public key moves to
+/// the versioned key registry.
+pub mod v1 {
+    use super::*;
+
+    /// Engine id given to aggregations stored before engine ids were recorded
+    pub const LEGACY_ENGINE_ID: &[u8] = b"legacy";
+
+    #[storage_alias]
+    type JointPublicKey<T: Config> = StorageValue<Pallet<T>, BoundedVec<u8, ConstU32<128>>>;
+
+    /// Moves the stored `JointPublicKey` into `JointPublicKeys` under version 0,
+    /// which is the default `CurrentJointKeyVersion`, and translates baseline
+    /// records (see [`translate_aggregations`], [`translate_requests`] and
+    /// [`translate_committee`]) and backfills the counters derived from them
+    /// ([`backfill_requester_statistics`], [`backfill_pending_request_count`]).
+    pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let weight = translate_aggregations::<T>()
+                .saturating_add(translate_requests::<T>())
+                .saturating_add(translate_committee::<T>())
+                .saturating_add(backfill_requester_statistics::<T>())
+                .saturating_add(backfill_pending_request_count::<T>());
+
+            let Some(key) = JointPublicKey::<T>::take() else {
+                return weight.saturating_add(T::DbWeight::get().reads(1));
+            };
+
+            if !key.is_empty() {
+                JointPublicKeys::<T>::insert(0, key);
+            }
+
+            weight.saturating_add(T::DbWeight::get().reads_writes(1, 2))
+        }
+    }
+
+    /// Gives every aggregation [`LEGACY_ENGINE_ID`] and no request id, and
+    /// seeds `AggregatedIVSHistory` with it, since the current score is
+    /// always the newest history entry.
+    pub fn translate_aggregations<T: Config>() -> Weight {
+        let mut translated: u64 = 0;
+        v2_aggregated::AggregatedIVSScores::<T>::translate::<v0::AggregatedIVS, _>(|user, old| {
+            translated = translated.saturating_add(1);
+            let aggregated = old.upgrade();
+            v2_aggregated::AggregatedIVSHistory::<T>::insert(
+                user,
+                BoundedVec::truncate_from(vec![aggregated.clone()]),
+            );
+            Some(aggregated)
+        });
+
+        T::DbWeight::get().reads_writes(translated, translated.saturating_mul(2))
+    }
+
+    /// Gives every recompute request the fields added since the original
+    /// release, unset, so `iter()` callers such as request expiry see them.
+    pub fn translate_requests<T: Config>() -> Weight {
+        let mut translated: u64 = 0;
+        RecomputeRequests::<T>::translate::<v0::RecomputeRequest, _>(|_, old| {
+            translated = translated.saturating_add(1);
+            Some(old.upgrade())
+        });
+
+        T::DbWeight::get().reads_writes(translated, translated)
+    }
+
+    /// Gives every committee member the fields added since the original
+    /// release (see `v0::CommitteeMember::upgrade`).
+    pub fn translate_committee<T: Config>() -> Weight {
+        let now = frame_system::Pallet::<T>::block_number().saturated_into::<u64>();
+        let mut translated: u64 = 0;
+        Committee::<T>::translate::<v0::CommitteeMember<T::AccountId>, _>(|_, old| {
+            translated = translated.saturating_add(1);
+            Some(old.upgrade(now))
+        });
+
+        T::DbWeight::get().reads_writes(translated.saturating_add(1), translated)
+    }
+
+    /// Rebuilds `RequesterStatistics` from the stored requests, which the
+    /// original release did not count. Must run after [`translate_requests`].
+    pub fn backfill_requester_statistics<T: Config>() -> Weight {
+        let mut stats: BTreeMap<BoundedVec<u8, ConstU32<128>>, RequesterStats> = BTreeMap::new();
+        let mut requests: u64 = 0;
+        for request in RecomputeRequests::<T>::iter_values() {
+            requests = requests.saturating_add(1);
+            let entry = stats.entry(request.requester).or_default();
+            entry.total_requested = entry.total_requested.saturating_add(1);
+            let counter = match request.status {
+                RequestStatus::Pending | RequestStatus::InProgress => &mut entry.pending,
+                RequestStatus::Completed => &mut entry.completed,
+                RequestStatus::Failed => &mut entry.failed,
+                RequestStatus::Cancelled => &mut entry.cancelled,
+            };
+            *counter = counter.saturating_add(1);
+        }
+
+        let requesters = stats.len() as u64;
+        for (requester, entry) in stats {
+            RequesterStatistics::<T>::insert(requester, entry);
+        }
+
+        T::DbWeight::get().reads_writes(requests, requesters)
+    }
+
+    /// Sets `PendingRequestCount` to the number of stored `Pending` requests,
+    /// which the original release did not count. Must run after
+    /// [`translate_requests`].
+    pub fn backfill_pending_request_count<T: Config>() -> Weight {
+        let mut requests: u64 = 0;
+        let mut pending: u32 = 0;
+        for request in RecomputeRequests::<T>::iter_values() {
+            requests = requests.saturating_add(1);
+            if matches!(request.status, RequestStatus::Pending) {
+                pending = pending.saturating_add(1);
+            }
+        }
+        PendingRequestCount::<T>::put(pending);
+
+        T::DbWeight::get().reads_writes(requests, 1)
+    }
+
+    /// [`InnerMigrateV0ToV1`] gated on the on-chain storage version being 0.
+    pub type MigrateV0ToV1<T> = VersionedMigration<
+        0,
+        1,
+        InnerMigrateV0ToV1<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration seeding CID reference counts.
+pub mod v2 {
+    use super::*;
+
+    /// Counts one `CidRefCount` reference per `AggregatedIVSHistory` entry.
+    pub struct InnerMigrateV1ToV2<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV1ToV2<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut histories: u64 = 0;
+            let mut counted: u64 = 0;
+            for history in v2_aggregated::AggregatedIVSHistory::<T>::iter_values() {
+                histories = histories.saturating_add(1);
+                for entry in history {
+                    counted = counted.saturating_add(1);
+                    CidRefCount::<T>::mutate(entry.cid, |count| *count = count.saturating_add(1));
+                }
+            }
+
+            T::DbWeight::get().reads_writes(histories.saturating_add(counted), counted)
+        }
+    }
+
+    /// [`InnerMigrateV1ToV2`] gated on the on-chain storage version being 1.
+    pub type MigrateV1ToV2<T> = VersionedMigration<
+        1,
+        2,
+        InnerMigrateV1ToV2<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration adding typed scoring parameters to aggregations.
+pub mod v3 {
+    use super::*;
+
+    /// Gives every stored `AggregatedIVS`, current and historical, zeroed
+    /// `ScoringParams`.
+    pub struct InnerMigrateV2ToV3<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV2ToV3<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            AggregatedIVSScores::<T>::translate::<v2_aggregated::AggregatedIVS, _>(|_, old| {
+                translated = translated.saturating_add(1);
+                Some(old.upgrade())
+            });
+            AggregatedIVSHistory::<T>::translate::<
+                BoundedVec<v2_aggregated::AggregatedIVS, ConstU32<32>>,
+                _,
+            >(|_, old| {
+                translated = translated.saturating_add(1);
+                let history: Vec<_> = old.into_iter().map(|entry| entry.upgrade()).collect();
+                // Same bound as before, so nothing is truncated
+                Some(BoundedVec::truncate_from(history))
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV2ToV3`] gated on the on-chain storage version being 2.
+    pub type MigrateV2ToV3<T> = VersionedMigration<
+        2,
+        3,
+        InnerMigrateV2ToV3<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
+
+/// Migration adding the previous window to recompute rate limits.
+pub mod v4 {
+    use super::*;
+
+    /// Extends every `RequestRateLimits` entry with an empty previous window.
+    pub struct InnerMigrateV3ToV4<T>(core::marker::PhantomData<T>);
+
+    impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV3ToV4<T> {
+        fn on_runtime_upgrade() -> Weight {
+            let mut translated: u64 = 0;
+            RequestRateLimits::<T>::translate::<(u64, u32), _>(|_, (window_start, count)| {
+                translated = translated.saturating_add(1);
+                Some((window_start, count, 0))
+            });
+
+            T::DbWeight::get().reads_writes(translated, translated)
+        }
+    }
+
+    /// [`InnerMigrateV3ToV4`] gated on the on-chain storage version being 3.
+    pub type MigrateV3ToV4<T> = VersionedMigration<
+        3,
+        4,
+        InnerMigrateV3ToV4<T>,
+        Pallet<T>,
+        <T as frame_system::Config>::DbWeight,
+    >;
+}
@@ -0,0 +1,55 @@
+//! Mock runtime for pallet-ivs-aggregator tests and benchmarks
+
+use crate as pallet_ivs_aggregator;
+use frame_support::{
+    derive_impl,
+    traits::{ConstU32, ConstU64},
+};
+use sp_runtime::BuildStorage;
+
+type Block = frame_system::mocking::MockBlock<Test>;
+
+frame_support::construct_runtime!(
+    pub enum Test {
+        System: frame_system,
+        IvsAggregator: pallet_ivs_aggregator,
+    }
+);
+
+#[derive_impl(frame_system::config_preludes::TestDefaultConfig)]
+impl frame_system::Config for Test {
+    type Block = Block;
+}
+
+impl pallet_ivs_aggregator::Config for Test {
+    type RuntimeEvent = RuntimeEvent;
+    type WeightInfo = ();
+    type ComputeOrigin = pallet_ivs_aggregator::DefaultComputeOrigin<Test>;
+    type MaxCommitteeSize = ConstU32<20>;
+    type MaxDiseases = ConstU32<4>;
+    type CommitteeGracePeriod = ConstU64<5>;
+    type RetryBackoffBase = ConstU64<2>;
+    type MaxRetries = ConstU32<3>;
+    type ResetTimelock = ConstU64<10>;
+    type MinCommitteeForPolicy = ConstU32<3>;
+    type MaxInvariantScan = ConstU32<20>;
+    type AllowedCidPrefixes = ivs_primitives::DefaultCidPrefixes;
+    type MaxDecryptionBatch = ConstU32<10>;
+    type DefaultRecomputeCadence = ConstU64<100>;
+    type MaxRequestsPerWindow = ConstU32<5>;
+    type RequestWindowBlocks = ConstU64<10>;
+    type MaxAuditLogEntries = ConstU32<5>;
+    type RequestExpiryBlocks = ConstU64<50>;
+    type MaxExpiryScan = ConstU32<10>;
+    type MaxCompletionBatch = ConstU32<5>;
+}
+
+/// Externalities with an empty genesis, starting at block 1 so events are kept
+pub fn new_test_ext() -> sp_io::TestExternalities {
+    let storage = frame_system::GenesisConfig::<Test>::default()
+        .build_storage()
+        .unwrap();
+    let mut ext = sp_io::TestExternalities::new(storage);
+    ext.execute_with(|| System::set_block_number(1));
+    ext
+}
@@ -0,0 +1,1006 @@
+//! Unit tests for pallet-ivs-aggregator
+
+use crate::{
+    mock::*, Committee, CommitteeRole, CommitteeSize, DecryptionDecision, Error, Event,
+    InvariantCode, JointPublicKeys, RecomputeRequests, RequestStatus, RequesterStats,
+    ScoringParams,
+};
+use frame_support::{assert_noop, assert_ok};
+use ivs_primitives::ParticipationMetrics;
+
+/// Add `who` to the committee as a decryptor
+fn add_member(who: u64) {
+    assert_ok!(IvsAggregator::add_committee_member(
+        RuntimeOrigin::root(),
+        who,
+        b"member".to_vec(),
+        b"share".to_vec(),
+        CommitteeRole::Decryptor,
+    ));
+}
+
+/// A well-formed base32 CIDv1 accepted by the default `AllowedCidPrefixes`
+fn cid(tag: &[u8]) -> Vec<u8> {
+    let mut cid = b"bafy".to_vec();
+    cid.extend_from_slice(tag);
+    cid
+}
+
+/// Queue a recompute request for one disease from `who` and return its id
+fn request(who: u64) -> u64 {
+    let request_id = IvsAggregator::next_request_id_peek();
+    assert_ok!(IvsAggregator::request_recompute(
+        RuntimeOrigin::signed(who),
+        vec![b"covid".to_vec()],
+        None,
+        None,
+        None,
+    ));
+    request_id
+}
+
+/// `n` distinct disease ids
+fn diseases(n: u8) -> Vec<Vec<u8>> {
+    (0..n).map(|i| vec![b'd', i]).collect()
+}
+
+/// Store an aggregation of one disease for `user` produced by `engine`
+fn store(user: u64, engine: &[u8]) {
+    assert_ok!(IvsAggregator::store_aggregated_ivs(
+        RuntimeOrigin::root(),
+        user,
+        cid(b"agg"),
+        diseases(1),
+        Vec::new(),
+        engine.to_vec(),
+        ScoringParams::default(),
+    ));
+}
+
+#[test]
+fn same_client_nonce_yields_one_request() {
+    new_test_ext().execute_with(|| {
+        for _ in 0..2 {
+            assert_ok!(IvsAggregator::request_recompute(
+                RuntimeOrigin::signed(1),
+                vec![b"covid".to_vec()],
+                None,
+                Some(7),
+                None,
+            ));
+        }
+
+        assert_eq!(RecomputeRequests::<Test>::iter().count(), 1);
+        assert_eq!(IvsAggregator::request_by_nonce((1, 7)), Some(0));
+        System::assert_last_event(Event::RecomputeDeduplicated { request_id: 0 }.into());
+
+        // The nonce is scoped to the requester
+        assert_ok!(IvsAggregator::request_recompute(
+            RuntimeOrigin::signed(2),
+            vec![b"covid".to_vec()],
+            None,
+            Some(7),
+            None,
+        ));
+        assert_eq!(RecomputeRequests::<Test>::iter().count(), 2);
+        // Ids stay sequential; the nonce index maps each nonce to its id
+        assert_eq!(IvsAggregator::request_by_nonce((2, 7)), Some(1));
+
+        // A retry after other requests still resolves to the original id
+        assert_ok!(IvsAggregator::request_recompute(
+            RuntimeOrigin::signed(1),
+            vec![b"covid".to_vec()],
+            None,
+            Some(7),
+            None,
+        ));
+        System::assert_last_event(Event::RecomputeDeduplicated { request_id: 0 }.into());
+        assert_eq!(IvsAggregator::next_request_id_peek(), 2);
+    });
+}
+
+#[test]
+fn rate_limit_holds_across_window_boundary() {
+    new_test_ext().execute_with(|| {
+        let try_request = || {
+            IvsAggregator::request_recompute(
+                RuntimeOrigin::signed(1),
+                vec![b"covid".to_vec()],
+                None,
+                None,
+                None,
+            )
+        };
+
+        // Mock limit: 5 requests per 10 blocks
+        System::set_block_number(9);
+        for _ in 0..5 {
+            request(1);
+        }
+        assert_noop!(try_request(), Error::<Test>::RateLimited);
+
+        // A fresh fixed window would allow another 5 here
+        System::set_block_number(10);
+        assert_noop!(try_request(), Error::<Test>::RateLimited);
+
+        // Halfway through, half of the previous window still counts
+        System::set_block_number(15);
+        for _ in 0..3 {
+            request(1);
+        }
+        assert_noop!(try_request(), Error::<Test>::RateLimited);
+
+        // Other accounts have their own limit
+        request(2);
+
+        // Once a whole window has passed nothing carries over
+        System::set_block_number(30);
+        for _ in 0..5 {
+            request(1);
+        }
+        assert_noop!(try_request(), Error::<Test>::RateLimited);
+    });
+}
+
+#[test]
+fn key_compromise_is_declared_by_a_quorum_majority() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=4 {
+            add_member(who);
+        }
+        System::set_block_number(6);
+        // Still in its grace period, so its flag is not counted
+        add_member(5);
+
+        assert_noop!(
+            IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(9)),
+            Error::<Test>::CommitteeMemberNotFound
+        );
+
+        assert_ok!(IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(1)));
+        System::assert_last_event(Event::KeyCompromiseFlagged { member: 1 }.into());
+        assert_eq!(IvsAggregator::key_compromise_flag(1), Some(6));
+        assert_noop!(
+            IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(1)),
+            Error::<Test>::AlreadyFlagged
+        );
+
+        assert_ok!(IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(5)));
+        assert_ok!(IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(2)));
+        assert_eq!(IvsAggregator::key_compromise_flag_count(), (2, 4));
+        System::assert_last_event(Event::KeyCompromiseFlagged { member: 2 }.into());
+
+        // Three of the four members counting toward quorum
+        assert_ok!(IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(3)));
+        System::assert_last_event(Event::KeyCompromiseDeclared { flags: 3 }.into());
+
+        // Declared once, not again on later flags
+        assert_ok!(IvsAggregator::flag_key_compromise(RuntimeOrigin::signed(4)));
+        System::assert_last_event(Event::KeyCompromiseFlagged { member: 4 }.into());
+    });
+}
+
+#[test]
+fn new_member_counts_toward_quorum_after_grace_period() {
+    new_test_ext().execute_with(|| {
+        add_member(1);
+        let member = IvsAggregator::committee_member(1).unwrap();
+        assert_eq!(member.active_after, 6);
+
+        System::set_block_number(5);
+        assert!(!IvsAggregator::counts_toward_quorum(&member));
+        assert_eq!(IvsAggregator::quorum_member_count(), 0);
+
+        System::set_block_number(6);
+        assert!(IvsAggregator::counts_toward_quorum(&member));
+        assert_eq!(IvsAggregator::quorum_member_count(), 1);
+    });
+}
+
+#[test]
+fn manifest_is_recorded_on_in_progress_request() {
+    new_test_ext().execute_with(|| {
+        let id = request(1);
+        assert_noop!(
+            IvsAggregator::set_request_manifest(RuntimeOrigin::root(), id, cid(b"manifest")),
+            Error::<Test>::RequestNotInProgress
+        );
+
+        assert_ok!(IvsAggregator::claim_recompute_request(
+            RuntimeOrigin::root(),
+            id
+        ));
+        assert_ok!(IvsAggregator::set_request_manifest(
+            RuntimeOrigin::root(),
+            id,
+            cid(b"manifest")
+        ));
+
+        assert_eq!(IvsAggregator::request_manifest(id), Some(cid(b"manifest")));
+        System::assert_last_event(Event::RequestManifestSet { request_id: id }.into());
+    });
+}
+
+#[test]
+fn retry_backoff_doubles_until_retries_run_out() {
+    new_test_ext().execute_with(|| {
+        let id = request(1);
+        for (retry_count, backoff) in [(1, 2), (2, 4), (3, 8)] {
+            assert_ok!(IvsAggregator::fail_recompute_request(
+                RuntimeOrigin::root(),
+                id,
+                b"worker crashed".to_vec()
+            ));
+            assert_ok!(IvsAggregator::retry_recompute_request(
+                RuntimeOrigin::root(),
+                id
+            ));
+
+            let request = IvsAggregator::recompute_request(id).unwrap();
+            assert_eq!(request.retry_count, retry_count);
+            assert_eq!(request.next_retry_at, Some(1 + backoff));
+            assert_eq!(request.status, RequestStatus::Pending);
+        }
+
+        assert_ok!(IvsAggregator::fail_recompute_request(
+            RuntimeOrigin::root(),
+            id,
+            b"worker crashed".to_vec()
+        ));
+        assert_noop!(
+            IvsAggregator::retry_recompute_request(RuntimeOrigin::root(), id),
+            Error::<Test>::RetryLimitReached
+        );
+    });
+}
+
+#[test]
+fn completion_stores_and_emits_proof_cid() {
+    new_test_ext().execute_with(|| {
+        let id = request(1);
+        assert_noop!(
+            IvsAggregator::complete_recompute_request(
+                RuntimeOrigin::root(),
+                id,
+                Some(cid(&[b'a'; 125])),
+                None,
+                None,
+            ),
+            Error::<Test>::InvalidCID
+        );
+
+        assert_ok!(IvsAggregator::complete_recompute_request(
+            RuntimeOrigin::root(),
+            id,
+            Some(cid(b"proof")),
+            None,
+            None,
+        ));
+        let request = IvsAggregator::recompute_request(id).unwrap();
+        assert_eq!(request.proof_cid.unwrap().to_vec(), cid(b"proof"));
+        System::assert_last_event(
+            Event::RecomputeCompleted {
+                request_id: id,
+                proof_cid: Some(cid(b"proof")),
+                result_cid: None,
+                at: 1,
+            }
+            .into(),
+        );
+
+        // A finalized request keeps its proof
+        assert_noop!(
+            IvsAggregator::complete_recompute_request(
+                RuntimeOrigin::root(),
+                id,
+                Some(cid(b"other")),
+                None,
+                None,
+            ),
+            Error::<Test>::RequestAlreadyFinalized
+        );
+    });
+}
+
+#[test]
+fn committee_reset_waits_for_timelock() {
+    new_test_ext().execute_with(|| {
+        add_member(1);
+        add_member(2);
+        let new_members = vec![(3, b"three".to_vec(), b"share".to_vec())];
+
+        assert_noop!(
+            IvsAggregator::execute_committee_reset(RuntimeOrigin::root(), new_members.clone()),
+            Error::<Test>::ResetNotScheduled
+        );
+
+        assert_ok!(IvsAggregator::schedule_committee_reset(
+            RuntimeOrigin::root()
+        ));
+        assert_eq!(IvsAggregator::committee_reset_available_at(), Some(11));
+
+        System::set_block_number(10);
+        assert_noop!(
+            IvsAggregator::execute_committee_reset(RuntimeOrigin::root(), new_members.clone()),
+            Error::<Test>::ResetTimelocked
+        );
+
+        System::set_block_number(11);
+        assert_ok!(IvsAggregator::execute_committee_reset(
+            RuntimeOrigin::root(),
+            new_members
+        ));
+        assert_eq!(IvsAggregator::get_committee_members(), vec![3]);
+        assert_eq!(IvsAggregator::committee_size(), 1);
+        assert_eq!(IvsAggregator::committee_reset_available_at(), None);
+        System::assert_last_event(Event::CommitteeReset { members: 1 }.into());
+    });
+}
+
+#[test]
+fn max_diseases_is_enforced_on_store() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IvsAggregator::store_aggregated_ivs(
+            RuntimeOrigin::root(),
+            1,
+            cid(b"agg"),
+            diseases(4),
+            Vec::new(),
+            b"engine".to_vec(),
+            ScoringParams::default(),
+        ));
+        assert_eq!(
+            IvsAggregator::aggregated_ivs(1).unwrap().disease_ids.len(),
+            4
+        );
+
+        assert_noop!(
+            IvsAggregator::store_aggregated_ivs(
+                RuntimeOrigin::root(),
+                1,
+                cid(b"agg"),
+                diseases(5),
+                Vec::new(),
+                b"engine".to_vec(),
+                ScoringParams::default(),
+            ),
+            Error::<Test>::TooManyDiseases
+        );
+        assert_noop!(
+            IvsAggregator::request_recompute(
+                RuntimeOrigin::signed(1),
+                diseases(5),
+                None,
+                None,
+                None
+            ),
+            Error::<Test>::TooManyDiseases
+        );
+    });
+}
+
+#[test]
+fn last_completed_request_tracks_latest_completion() {
+    new_test_ext().execute_with(|| {
+        let first = request(1);
+        let second = request(1);
+        assert_eq!(IvsAggregator::last_completed_request(), None);
+
+        for id in [second, first] {
+            assert_ok!(IvsAggregator::complete_recompute_request(
+                RuntimeOrigin::root(),
+                id,
+                None,
+                None,
+                None,
+            ));
+        }
+        assert_eq!(IvsAggregator::last_completed_request(), Some(first));
+    });
+}
+
+#[test]
+fn requester_stats_follow_the_request_lifecycle() {
+    new_test_ext().execute_with(|| {
+        let completed = request(1);
+        let failed = request(1);
+        let cancelled = request(1);
+        let pending = request(1);
+        assert_ok!(IvsAggregator::claim_recompute_request(
+            RuntimeOrigin::root(),
+            pending
+        ));
+
+        assert_ok!(IvsAggregator::complete_recompute_request(
+            RuntimeOrigin::root(),
+            completed,
+            None,
+            None,
+            None,
+        ));
+        assert_ok!(IvsAggregator::fail_recompute_request(
+            RuntimeOrigin::root(),
+            failed,
+            b"timeout".to_vec()
+        ));
+        assert_ok!(IvsAggregator::cancel_recompute_request(
+            RuntimeOrigin::signed(1),
+            cancelled
+        ));
+
+        assert_eq!(
+            IvsAggregator::requester_stats(b"1".to_vec()),
+            RequesterStats {
+                total_requested: 4,
+                pending: 1,
+                completed: 1,
+                failed: 1,
+                cancelled: 1
+            }
+        );
+        assert_eq!(
+            IvsAggregator::requester_stats(b"2".to_vec()),
+            RequesterStats::default()
+        );
+    });
+}
+
+#[test]
+fn aggregation_changed_since_compares_computed_at() {
+    new_test_ext().execute_with(|| {
+        assert!(!IvsAggregator::aggregation_changed_since(&1, 0));
+
+        System::set_block_number(3);
+        store(1, b"engine");
+
+        assert!(IvsAggregator::aggregation_changed_since(&1, 2));
+        assert!(!IvsAggregator::aggregation_changed_since(&1, 3));
+    });
+}
+
+#[test]
+fn decryption_policy_needs_minimum_committee() {
+    new_test_ext().execute_with(|| {
+        add_member(1);
+        add_member(2);
+        assert_noop!(
+            IvsAggregator::set_decryption_policy(RuntimeOrigin::root(), vec![1], 2, 2, None),
+            Error::<Test>::CommitteeTooSmall
+        );
+
+        add_member(3);
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            2,
+            None
+        ));
+        assert_eq!(IvsAggregator::decryption_policy().unwrap().threshold, 2);
+    });
+}
+
+#[test]
+fn verify_invariants_reports_counter_drift() {
+    new_test_ext().execute_with(|| {
+        add_member(1);
+        assert_ok!(IvsAggregator::verify_invariants(RuntimeOrigin::root()));
+        assert!(!System::events().iter().any(|record| matches!(
+            record.event,
+            RuntimeEvent::IvsAggregator(Event::InvariantViolation { .. })
+        )));
+
+        CommitteeSize::<Test>::put(2);
+        assert_ok!(IvsAggregator::verify_invariants(RuntimeOrigin::root()));
+        System::assert_last_event(
+            Event::InvariantViolation {
+                code: InvariantCode::CommitteeSizeMismatch,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn aggregations_filter_by_engine() {
+    new_test_ext().execute_with(|| {
+        store(1, b"alpha");
+        store(2, b"beta");
+        store(3, b"alpha");
+
+        let (mut users, next) = IvsAggregator::aggregations_by_engine(b"alpha".to_vec(), 10, None);
+        users.sort();
+        assert_eq!(users, vec![1, 3]);
+        assert_eq!(next, None);
+        assert_eq!(
+            IvsAggregator::aggregated_ivs(2).unwrap().engine_id.to_vec(),
+            b"beta".to_vec()
+        );
+    });
+}
+
+#[test]
+fn next_request_id_peek_matches_assigned_id() {
+    new_test_ext().execute_with(|| {
+        request(1);
+        let peeked = IvsAggregator::next_request_id_peek();
+        assert_eq!(IvsAggregator::next_request_id_peek(), peeked);
+
+        request(1);
+        System::assert_has_event(
+            Event::RecomputeRequested {
+                request_id: peeked,
+                requester: b"1".to_vec(),
+                diseases: vec![b"covid".to_vec()],
+                algorithm_params: None,
+                at: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn suspension_keeps_decryption_threshold() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        System::set_block_number(6);
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            3,
+            None
+        ));
+
+        assert_ok!(IvsAggregator::set_committee_member_active(
+            RuntimeOrigin::root(),
+            3,
+            false
+        ));
+        assert_noop!(
+            IvsAggregator::set_committee_member_active(RuntimeOrigin::root(), 2, false),
+            Error::<Test>::WouldBreakThreshold
+        );
+
+        // The suspended member keeps its key share
+        let member = IvsAggregator::committee_member(3).unwrap();
+        assert!(!member.is_active);
+        assert_eq!(member.key_share_id.to_vec(), b"share".to_vec());
+    });
+}
+
+#[test]
+fn stale_members_are_deactivated() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        System::set_block_number(20);
+        assert_ok!(IvsAggregator::committee_heartbeat(RuntimeOrigin::signed(3)));
+
+        assert_ok!(IvsAggregator::deactivate_stale_committee(
+            RuntimeOrigin::root(),
+            10,
+            10
+        ));
+        System::assert_last_event(Event::StaleCommitteeDeactivated { count: 2 }.into());
+        assert_eq!(IvsAggregator::get_active_committee_members(), vec![3]);
+    });
+}
+
+#[test]
+fn stale_deactivation_keeps_decryption_threshold() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        System::set_block_number(6);
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            3,
+            None
+        ));
+
+        System::set_block_number(20);
+        assert_ok!(IvsAggregator::deactivate_stale_committee(
+            RuntimeOrigin::root(),
+            10,
+            10
+        ));
+        System::assert_last_event(Event::StaleCommitteeDeactivated { count: 1 }.into());
+        assert_eq!(IvsAggregator::get_active_committee_members().len(), 2);
+    });
+}
+
+#[test]
+fn requester_allowlist_gates_recompute_requests() {
+    new_test_ext().execute_with(|| {
+        // An empty allow-list is open to everyone
+        request(2);
+
+        assert_ok!(IvsAggregator::add_allowed_requester(
+            RuntimeOrigin::root(),
+            1
+        ));
+        request(1);
+        assert_noop!(
+            IvsAggregator::request_recompute(
+                RuntimeOrigin::signed(2),
+                vec![b"covid".to_vec()],
+                None,
+                None,
+                None,
+            ),
+            Error::<Test>::NotAuthorized
+        );
+    });
+}
+
+#[test]
+fn decryption_check_reports_each_decision() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        assert_eq!(
+            IvsAggregator::decryption_check(&1, &9),
+            DecryptionDecision::NoPolicy
+        );
+
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            3,
+            Some(20)
+        ));
+        // Members are still in their grace period
+        assert_eq!(
+            IvsAggregator::decryption_check(&1, &9),
+            DecryptionDecision::NoQuorum
+        );
+
+        System::set_block_number(6);
+        assert_eq!(
+            IvsAggregator::decryption_check(&1, &9),
+            DecryptionDecision::Allowed
+        );
+        assert_eq!(
+            IvsAggregator::decryption_check(&2, &9),
+            DecryptionDecision::NotAuthorized
+        );
+
+        System::set_block_number(21);
+        assert_eq!(
+            IvsAggregator::decryption_check(&1, &9),
+            DecryptionDecision::Expired
+        );
+    });
+}
+
+#[test]
+fn participation_metrics_match_storage() {
+    new_test_ext().execute_with(|| {
+        add_member(1);
+        add_member(2);
+        let claimed = request(1);
+        request(1);
+        assert_ok!(IvsAggregator::claim_recompute_request(
+            RuntimeOrigin::root(),
+            claimed
+        ));
+
+        assert_eq!(<IvsAggregator as ParticipationMetrics>::committee_size(), 2);
+        assert_eq!(Committee::<Test>::iter().count(), 2);
+        assert_eq!(
+            <IvsAggregator as ParticipationMetrics>::pending_request_count(),
+            1
+        );
+        assert_eq!(
+            IvsAggregator::get_requests_by_status(RequestStatus::Pending).len(),
+            1
+        );
+        assert_eq!(<IvsAggregator as ParticipationMetrics>::user_count(), 0);
+    });
+}
+
+#[test]
+fn expiring_members_are_ordered_soonest_first() {
+    new_test_ext().execute_with(|| {
+        for (who, term_end) in [(1, 30), (2, 15), (3, 100)] {
+            add_member(who);
+            assert_ok!(IvsAggregator::set_committee_term_end(
+                RuntimeOrigin::root(),
+                who,
+                Some(term_end)
+            ));
+        }
+        add_member(4);
+
+        assert_eq!(
+            IvsAggregator::expiring_committee_members(50, 1, 10),
+            vec![(2, 15), (1, 30)]
+        );
+        assert_eq!(
+            IvsAggregator::expiring_committee_members(50, 1, 1),
+            vec![(2, 15)]
+        );
+    });
+}
+
+#[test]
+fn decryption_batch_follows_policy_expiry() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            3,
+            Some(20)
+        ));
+        System::set_block_number(6);
+
+        assert_eq!(
+            IvsAggregator::can_decrypt_batch(&1, vec![7, 8]),
+            vec![(7, true), (8, true)]
+        );
+
+        System::set_block_number(21);
+        assert_eq!(
+            IvsAggregator::can_decrypt_batch(&1, vec![7, 8]),
+            vec![(7, false), (8, false)]
+        );
+    });
+}
+
+#[test]
+fn key_history_records_rotations_in_order() {
+    new_test_ext().execute_with(|| {
+        for (block, tag) in [(1, b"one"), (2, b"two"), (3, b"six")] {
+            System::set_block_number(block);
+            assert_ok!(IvsAggregator::update_joint_public_key(
+                RuntimeOrigin::root(),
+                cid(tag)
+            ));
+        }
+
+        let history: Vec<(Vec<u8>, u64)> = IvsAggregator::key_history()
+            .into_iter()
+            .map(|(key, at)| (key.to_vec(), at))
+            .collect();
+        assert_eq!(
+            history,
+            vec![(cid(b"one"), 1), (cid(b"two"), 2), (cid(b"six"), 3)]
+        );
+        assert_eq!(IvsAggregator::current_joint_key_version(), 3);
+        assert_eq!(IvsAggregator::joint_public_key().to_vec(), cid(b"six"));
+    });
+}
+
+#[test]
+fn reactivation_resets_liveness() {
+    new_test_ext().execute_with(|| {
+        add_member(1);
+        assert_noop!(
+            IvsAggregator::reactivate_committee_member(RuntimeOrigin::root(), 1),
+            Error::<Test>::NotSuspended
+        );
+        assert_ok!(IvsAggregator::set_committee_member_active(
+            RuntimeOrigin::root(),
+            1,
+            false
+        ));
+
+        System::set_block_number(30);
+        assert_ok!(IvsAggregator::reactivate_committee_member(
+            RuntimeOrigin::root(),
+            1
+        ));
+        let member = IvsAggregator::committee_member(1).unwrap();
+        assert!(member.is_active);
+        assert_eq!(member.last_seen, 30);
+
+        assert_ok!(IvsAggregator::deactivate_stale_committee(
+            RuntimeOrigin::root(),
+            10,
+            10
+        ));
+        assert_eq!(IvsAggregator::get_active_committee_members(), vec![1]);
+    });
+}
+
+#[test]
+fn short_cadence_goes_stale_first() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IvsAggregator::set_disease_cadence(
+            RuntimeOrigin::root(),
+            b"flu".to_vec(),
+            Some(5)
+        ));
+        for (user, disease) in [(1, b"flu".to_vec()), (2, b"covid".to_vec())] {
+            assert_ok!(IvsAggregator::store_aggregated_ivs(
+                RuntimeOrigin::root(),
+                user,
+                cid(b"agg"),
+                vec![disease],
+                Vec::new(),
+                b"engine".to_vec(),
+                ScoringParams::default(),
+            ));
+        }
+        assert!(!IvsAggregator::aggregation_is_stale(&1));
+
+        System::set_block_number(6);
+        assert!(IvsAggregator::aggregation_is_stale(&1));
+        assert!(!IvsAggregator::aggregation_is_stale(&2));
+
+        System::set_block_number(101);
+        assert!(IvsAggregator::aggregation_is_stale(&2));
+    });
+}
+
+#[test]
+fn only_decryptors_submit_shares() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        assert_ok!(IvsAggregator::add_committee_member(
+            RuntimeOrigin::root(),
+            4,
+            b"approver".to_vec(),
+            b"share".to_vec(),
+            CommitteeRole::Approver,
+        ));
+        System::set_block_number(6);
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            3,
+            None
+        ));
+        assert_ok!(IvsAggregator::open_decryption_session(
+            RuntimeOrigin::signed(1),
+            7
+        ));
+        System::assert_last_event(
+            Event::DecryptionSessionOpened {
+                session_id: 0,
+                requester: 1,
+                user: 7,
+            }
+            .into(),
+        );
+
+        assert_noop!(
+            IvsAggregator::submit_decryption_share(RuntimeOrigin::signed(4), 0, b"s".to_vec()),
+            Error::<Test>::WrongRole
+        );
+        assert_ok!(IvsAggregator::submit_decryption_share(
+            RuntimeOrigin::signed(1),
+            0,
+            b"s".to_vec()
+        ));
+        System::assert_last_event(
+            Event::DecryptionShareSubmitted {
+                session_id: 0,
+                member: 1,
+            }
+            .into(),
+        );
+    });
+}
+
+#[test]
+fn aggregation_records_its_request() {
+    new_test_ext().execute_with(|| {
+        store(7, b"engine");
+        assert_eq!(IvsAggregator::request_for_aggregation(&7), None);
+
+        let id = request(1);
+        assert_ok!(IvsAggregator::complete_recompute_request(
+            RuntimeOrigin::root(),
+            id,
+            None,
+            None,
+            Some((
+                7,
+                cid(b"agg"),
+                Vec::new(),
+                b"engine".to_vec(),
+                ScoringParams::default()
+            )),
+        ));
+        assert_eq!(IvsAggregator::request_for_aggregation(&7), Some(id));
+        assert_eq!(IvsAggregator::request_for_aggregation(&8), None);
+    });
+}
+
+#[test]
+fn joint_key_versions_stay_valid_through_rotation() {
+    new_test_ext().execute_with(|| {
+        assert_ok!(IvsAggregator::register_joint_public_key(
+            RuntimeOrigin::root(),
+            1,
+            cid(b"one")
+        ));
+        assert_ok!(IvsAggregator::register_joint_public_key(
+            RuntimeOrigin::root(),
+            2,
+            cid(b"two")
+        ));
+        assert_noop!(
+            IvsAggregator::register_joint_public_key(RuntimeOrigin::root(), 1, cid(b"six")),
+            Error::<Test>::KeyVersionExists
+        );
+
+        assert_ok!(IvsAggregator::set_current_joint_key_version(
+            RuntimeOrigin::root(),
+            1
+        ));
+        assert_eq!(IvsAggregator::joint_public_key().to_vec(), cid(b"one"));
+        assert_ok!(IvsAggregator::set_current_joint_key_version(
+            RuntimeOrigin::root(),
+            2
+        ));
+        assert_eq!(IvsAggregator::joint_public_key().to_vec(), cid(b"two"));
+        // The previous version remains registered during rollover
+        assert!(JointPublicKeys::<Test>::contains_key(1));
+        assert_noop!(
+            IvsAggregator::set_current_joint_key_version(RuntimeOrigin::root(), 9),
+            Error::<Test>::UnknownKeyVersion
+        );
+
+        // A plain update takes the next version after the latest registered one
+        assert_ok!(IvsAggregator::update_joint_public_key(
+            RuntimeOrigin::root(),
+            cid(b"new")
+        ));
+        assert_eq!(IvsAggregator::current_joint_key_version(), 3);
+        assert_eq!(IvsAggregator::joint_public_key().to_vec(), cid(b"new"));
+    });
+}
+
+#[test]
+fn decryption_request_reports_partial_progress() {
+    new_test_ext().execute_with(|| {
+        for who in 1..=3 {
+            add_member(who);
+        }
+        System::set_block_number(6);
+        assert_ok!(IvsAggregator::set_decryption_policy(
+            RuntimeOrigin::root(),
+            vec![1],
+            2,
+            3,
+            None
+        ));
+        assert_ok!(IvsAggregator::open_decryption_session(
+            RuntimeOrigin::signed(1),
+            7
+        ));
+        assert_ok!(IvsAggregator::submit_decryption_share(
+            RuntimeOrigin::signed(2),
+            0,
+            b"s".to_vec()
+        ));
+
+        let view = IvsAggregator::decryption_request(0).unwrap();
+        assert_eq!(view.requester, 1);
+        assert_eq!(view.target_user, 7);
+        assert_eq!(view.created_at, 6);
+        assert_eq!(view.contributors, vec![2]);
+        assert_eq!(view.shares_remaining, 1);
+        assert!(!view.completed);
+        assert_eq!(IvsAggregator::decryption_request(1), None);
+    });
+}
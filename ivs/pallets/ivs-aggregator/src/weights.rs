@@ -0,0 +1,615 @@
+//! Weights for `pallet_ivs_aggregator`
+//!
+//! Hand-written conservative estimates, not benchmark output: no runtime
+//! includes this pallet yet, so it has not been benchmarked.
+//!
+//! Each weight is a rounded-up execution allowance plus the storage reads and
+//! writes of the call's worst-case path, as listed on each `WeightInfo` method.
+//! Replace this file with `frame-omni-bencher` output once the pallet can be
+//! benchmarked in a runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ivs_aggregator`.
+pub trait WeightInfo {
+	/// - `Committee`: 1 read, 1 write
+	/// - `CommitteeSize`: 1 read, 1 write
+	fn add_committee_member() -> Weight;
+	/// `d`: disease ids requested
+	/// - `RequesterAllowlist`: 1 read
+	/// - `RequestByNonce`: 1 read, 1 write
+	/// - `RequestRateLimits`: 1 read, 1 write
+	/// - `NextRequestId`: 1 read, 1 write
+	/// - `RequesterStatistics`: 1 read, 1 write
+	/// - `RecomputeRequests`: 1 write
+	/// - `PendingRequestCount`: 1 read, 1 write
+	/// - `RecomputePaused`: 1 read
+	fn request_recompute(d: u32, ) -> Weight;
+	/// `d`: disease ids in the aggregation
+	/// - `AggregatedIVSHistory`: 1 read, 1 write
+	/// - `AggregatedIVSScores`: 1 write
+	/// - `CidRefCount`: 2 reads, 2 writes
+	fn store_aggregated_ivs(d: u32, ) -> Weight;
+	/// `a`: authorized accounts
+	/// - `CommitteeSize`: 1 read
+	/// - `Committee`: `a` reads
+	/// - `CurrentDecryptionPolicy`: 1 write
+	fn set_decryption_policy(a: u32, ) -> Weight;
+	/// - `KeyHistory`: 1 read, 1 write
+	/// - `JointKeyVersion`: 1 read, 1 write
+	/// - `CurrentJointKeyVersion`: 1 read, 1 write
+	/// - `JointPublicKeys`: 1 write
+	/// - `LatestJointKeyVersion`: 1 read, 1 write
+	fn update_joint_public_key() -> Weight;
+	/// - `RecomputeRequests`: 1 read, 1 write
+	/// - `RequesterStatistics`: 1 read, 1 write
+	/// - `LastCompletedRequestId`: 1 write
+	/// - `PendingRequestCount`: 1 read, 1 write
+	fn complete_recompute_request() -> Weight;
+	/// - `RecomputeRequests`: 1 read, 1 write
+	fn set_request_manifest() -> Weight;
+	/// - `RecomputeRequests`: 1 read, 1 write
+	/// - `RequesterStatistics`: 1 read, 1 write
+	/// - `PendingRequestCount`: 1 read, 1 write
+	fn retry_recompute_request() -> Weight;
+	/// - `CommitteeResetAvailableAt`: 1 write
+	fn schedule_committee_reset() -> Weight;
+	/// `o`: members removed, `m`: members added
+	/// - `CommitteeResetAvailableAt`: 1 read, 1 write
+	/// - `Committee`: `m` reads, `o + m` writes
+	/// - `CommitteeSize`: 1 read, 1 write
+	fn execute_committee_reset(o: u32, m: u32, ) -> Weight;
+	/// `s`: entries scanned in each map
+	/// - `Committee`: `s + 1` reads
+	/// - `RecomputeRequests`: `s + 1` reads
+	/// - `RequesterStatistics`: `s + 1` reads
+	/// - `CommitteeSize`: 1 read
+	/// - `NextRequestId`: 1 read
+	fn verify_invariants(s: u32, ) -> Weight;
+	/// `m`: committee members counted to check the decryption threshold
+	/// - `Committee`: `m` reads, 1 write
+	/// - `CurrentDecryptionPolicy`: 1 read
+	/// - `CommitteeSize`: 1 read, 1 write
+	fn remove_committee_member(m: u32, ) -> Weight;
+	/// `m`: committee members counted to check the decryption threshold
+	/// - `Committee`: `m` reads, 1 write
+	/// - `CurrentDecryptionPolicy`: 1 read
+	fn set_committee_member_active(m: u32, ) -> Weight;
+	/// - `Committee`: 1 read, 1 write
+	fn committee_heartbeat() -> Weight;
+	/// `l`: stale members inspected, `m`: members counted to check the decryption threshold
+	/// - `Committee`: `l + m` reads, `l` writes
+	/// - `CurrentDecryptionPolicy`: 1 read
+	fn deactivate_stale_committee(l: u32, m: u32, ) -> Weight;
+	/// - `RecomputeRequests`: 1 read, 1 write
+	/// - `RequesterStatistics`: 1 read, 1 write
+	/// - `PendingRequestCount`: 1 read, 1 write
+	fn cancel_recompute_request() -> Weight;
+	/// - `RequesterAllowlist`: 1 write
+	fn add_allowed_requester() -> Weight;
+	/// - `RequesterAllowlist`: 1 write
+	fn remove_allowed_requester() -> Weight;
+	/// - `RecomputeRequests`: 1 read, 1 write
+	/// - `RequesterStatistics`: 1 read, 1 write
+	/// - `PendingRequestCount`: 1 read, 1 write
+	fn fail_recompute_request() -> Weight;
+	/// - `RecomputeRequests`: 1 read, 1 write
+	/// - `RequesterStatistics`: 1 read, 1 write
+	/// - `PendingRequestCount`: 1 read, 1 write
+	fn claim_recompute_request() -> Weight;
+	/// - `Committee`: 1 read, 1 write
+	fn set_committee_term_end() -> Weight;
+	/// `a`: authorized accounts
+	/// - `CommitteeSize`: 1 read
+	/// - `Committee`: `a` reads
+	/// - `UserDecryptionPolicies`: 1 write
+	fn set_user_decryption_policy(a: u32, ) -> Weight;
+	/// `m`: committee members counted to check quorum
+	/// - `UserDecryptionPolicies`: 1 read
+	/// - `CurrentDecryptionPolicy`: 1 read
+	/// - `Committee`: `m` reads
+	/// - `NextSessionId`: 1 read, 1 write
+	/// - `DecryptionSessions`: 1 write
+	fn open_decryption_session(m: u32, ) -> Weight;
+	/// `s`: shares already submitted to the session
+	/// - `Committee`: 1 read
+	/// - `DecryptionSessions`: 1 read, 1 write
+	/// - `UserDecryptionPolicies`: 1 read
+	/// - `CurrentDecryptionPolicy`: 1 read
+	fn submit_decryption_share(s: u32, ) -> Weight;
+	/// - `Committee`: 1 read, 1 write
+	fn reactivate_committee_member() -> Weight;
+	/// - `Committee`: 1 read, 1 write
+	/// - `JointKeyVersion`: 1 read, 1 write
+	fn rotate_key_share() -> Weight;
+	/// - `DiseaseCadence`: 1 write
+	fn set_disease_cadence() -> Weight;
+	/// `m`: committee members counted to check the decryption threshold
+	/// - `Committee`: `m` reads, 1 write
+	/// - `CurrentDecryptionPolicy`: 1 read
+	fn resign_committee(m: u32, ) -> Weight;
+	/// - `JointPublicKeys`: 1 read, 1 write
+	/// - `LatestJointKeyVersion`: 1 read, 1 write
+	fn register_joint_public_key() -> Weight;
+	/// - `JointPublicKeys`: 1 read
+	/// - `KeyHistory`: 1 read, 1 write
+	/// - `JointKeyVersion`: 1 read, 1 write
+	/// - `CurrentJointKeyVersion`: 1 write
+	fn set_current_joint_key_version() -> Weight;
+	/// `m`: committee members counted to check quorum
+	/// - `UserDecryptionPolicies`: 1 read
+	/// - `CurrentDecryptionPolicy`: 1 read
+	/// - `Committee`: `m` reads
+	/// - `NextAuditIndex`: 1 read, 1 write
+	/// - `DecryptionAuditLog`: 2 writes
+	fn record_decryption_access(m: u32, ) -> Weight;
+	/// `n`: requests completed
+	/// - `RecomputeRequests`: `n` reads, `n` writes
+	/// - `RequesterStatistics`: `n` reads, `n` writes
+	/// - `PendingRequestCount`: 1 read, 1 write
+	/// - `LastCompletedRequestId`: 1 write
+	fn complete_recompute_requests_batch(n: u32, ) -> Weight;
+	/// - `Committee`: 1 read, 1 write
+	fn update_committee_member_name() -> Weight;
+	/// - `RecomputePaused`: 1 write
+	fn pause_recompute() -> Weight;
+	/// - `RecomputePaused`: 1 write
+	fn unpause_recompute() -> Weight;
+	/// `m`: committee members counted to check the majority
+	/// - `Committee`: `m + 1` reads
+	/// - `KeyCompromiseFlags`: `m + 1` reads, 1 write
+	fn flag_key_compromise(m: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_ivs_aggregator` priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn add_committee_member() -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn request_recompute(d: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 5805)
+			.saturating_add(Weight::from_parts(420_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+	}
+	fn store_aggregated_ivs(d: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 53455)
+			.saturating_add(Weight::from_parts(390_000, 0).saturating_mul(d.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn set_decryption_policy(a: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(84_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(a.into()))
+	}
+	fn update_joint_public_key() -> Weight {
+		Weight::from_parts(30_000_000, 5870)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	fn complete_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	fn set_request_manifest() -> Weight {
+		Weight::from_parts(20_000_000, 5805)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn retry_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn schedule_committee_reset() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn execute_committee_reset(o: u32, m: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(o.into())))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn verify_invariants(s: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 4815).saturating_mul(s.into()))
+	}
+	fn remove_committee_member(m: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn set_committee_member_active(m: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn committee_heartbeat() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn deactivate_stale_committee(l: u32, m: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(Weight::from_parts(7_400_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(l.into())))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn cancel_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn add_allowed_requester() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remove_allowed_requester() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn fail_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn claim_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn set_committee_term_end() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_user_decryption_policy(a: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(92_000, 0).saturating_mul(a.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(a.into()))
+	}
+	fn open_decryption_session(m: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 4048)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn submit_decryption_share(s: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 32803)
+			.saturating_add(Weight::from_parts(210_000, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn reactivate_committee_member() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn rotate_key_share() -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_disease_cadence() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn resign_committee(m: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn register_joint_public_key() -> Weight {
+		Weight::from_parts(15_000_000, 3614)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn set_current_joint_key_version() -> Weight {
+		Weight::from_parts(25_000_000, 5870)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+	}
+	fn record_decryption_access(m: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 4048)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn complete_recompute_requests_batch(n: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5201).saturating_mul(n.into()))
+	}
+	fn update_committee_member_name() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn pause_recompute() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn unpause_recompute() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn flag_key_compromise(m: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5228).saturating_mul(m.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn add_committee_member() -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn request_recompute(d: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 5805)
+			.saturating_add(Weight::from_parts(420_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+	}
+	fn store_aggregated_ivs(d: u32, ) -> Weight {
+		Weight::from_parts(35_000_000, 53455)
+			.saturating_add(Weight::from_parts(390_000, 0).saturating_mul(d.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn set_decryption_policy(a: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(84_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(a.into()))
+	}
+	fn update_joint_public_key() -> Weight {
+		Weight::from_parts(30_000_000, 5870)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	fn complete_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	fn set_request_manifest() -> Weight {
+		Weight::from_parts(20_000_000, 5805)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn retry_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn schedule_committee_reset() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn execute_committee_reset(o: u32, m: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(Weight::from_parts(5_000_000, 0).saturating_mul(o.into()))
+			.saturating_add(Weight::from_parts(18_000_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(o.into())))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn verify_invariants(s: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(Weight::from_parts(7_000_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(s.into())))
+			.saturating_add(Weight::from_parts(0, 4815).saturating_mul(s.into()))
+	}
+	fn remove_committee_member(m: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_500_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn set_committee_member_active(m: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn committee_heartbeat() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn deactivate_stale_committee(l: u32, m: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1493)
+			.saturating_add(Weight::from_parts(7_400_000, 0).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(l.into())))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(l.into())))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(l.into()))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn cancel_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn add_allowed_requester() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_allowed_requester() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn fail_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn claim_recompute_request() -> Weight {
+		Weight::from_parts(25_000_000, 5805)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn set_committee_term_end() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_user_decryption_policy(a: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(92_000, 0).saturating_mul(a.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(a.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(a.into()))
+	}
+	fn open_decryption_session(m: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 4048)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn submit_decryption_share(s: u32, ) -> Weight {
+		Weight::from_parts(30_000_000, 32803)
+			.saturating_add(Weight::from_parts(210_000, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn reactivate_committee_member() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn rotate_key_share() -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_disease_cadence() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn resign_committee(m: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn register_joint_public_key() -> Weight {
+		Weight::from_parts(15_000_000, 3614)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn set_current_joint_key_version() -> Weight {
+		Weight::from_parts(25_000_000, 5870)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	}
+	fn record_decryption_access(m: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 4048)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(Weight::from_parts(0, 2814).saturating_mul(m.into()))
+	}
+	fn complete_recompute_requests_batch(n: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 1489)
+			.saturating_add(Weight::from_parts(14_000_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5201).saturating_mul(n.into()))
+	}
+	fn update_committee_member_name() -> Weight {
+		Weight::from_parts(15_000_000, 3804)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn pause_recompute() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn unpause_recompute() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn flag_key_compromise(m: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 3804)
+			.saturating_add(Weight::from_parts(3_400_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(Weight::from_parts(0, 5228).saturating_mul(m.into()))
+	}
+}
@@ -0,0 +1,13 @@
+//! # IVS Runtime API
+//!
+//! Read-only queries over the ivs pallet for off-chain clients.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+sp_api::decl_runtime_apis! {
+    /// Runtime API for querying ivs pallet state
+    pub trait IvsApi {
+        /// Number of registered users
+        fn user_count() -> u32;
+    }
+}
@@ -0,0 +1,100 @@
+//! Benchmarking setup for pallet-ivs
+
+use super::*;
+use alloc::{vec, vec::Vec};
+use frame_benchmarking::v2::*;
+use frame_support::{pallet_prelude::ConstU32, BoundedVec};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn register<T: Config>(who: &T::AccountId) {
+	Users::<T>::insert(who, ());
+}
+
+#[benchmarks]
+mod benchmarks {
+	use super::*;
+
+	#[benchmark]
+	fn register_user() {
+		let caller: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		register_user(RawOrigin::Signed(caller.clone()));
+
+		assert!(Users::<T>::contains_key(&caller));
+	}
+
+	// `c` is the length of both contact lists before the new edge is pushed.
+	#[benchmark]
+	fn add_contact(c: Linear<0, { MAX_CONTACTS - 1 }>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let contact: T::AccountId = account("contact", 0, SEED);
+		register::<T>(&caller);
+		register::<T>(&contact);
+
+		let filler: BoundedVec<T::AccountId, ConstU32<MAX_CONTACTS>> = (0..c)
+			.map(|i| account("filler", i, SEED))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("c is below MAX_CONTACTS; qed");
+		Contacts::<T>::insert(&caller, filler.clone());
+		Contacts::<T>::insert(&contact, filler);
+
+		#[extrinsic_call]
+		add_contact(RawOrigin::Signed(caller.clone()), contact.clone());
+
+		assert!(Contacts::<T>::get(&caller).contains(&contact));
+		assert!(Contacts::<T>::get(&contact).contains(&caller));
+	}
+
+	#[benchmark]
+	fn set_health_cid(l: Linear<1, 128>) {
+		let caller: T::AccountId = whitelisted_caller();
+		register::<T>(&caller);
+		let cid = vec![b'a'; l as usize];
+
+		#[extrinsic_call]
+		set_health_cid(RawOrigin::Signed(caller.clone()), cid);
+
+		assert!(HealthCid::<T>::contains_key(&caller));
+	}
+
+	#[benchmark]
+	fn set_ivs_cid() {
+		let user: T::AccountId = account("user", 0, SEED);
+		let cid = vec![b'a'; 128];
+
+		#[extrinsic_call]
+		set_ivs_cid(RawOrigin::Root, user.clone(), cid);
+
+		assert!(IvsCid::<T>::contains_key(&user));
+	}
+
+	// `c` is the length of both contact lists, with the removed edge last.
+	#[benchmark]
+	fn remove_contact(c: Linear<1, MAX_CONTACTS>) {
+		let caller: T::AccountId = whitelisted_caller();
+		let contact: T::AccountId = account("contact", 0, SEED);
+
+		let filler = |last: &T::AccountId| -> BoundedVec<T::AccountId, ConstU32<MAX_CONTACTS>> {
+			(1..c)
+				.map(|i| account("filler", i, SEED))
+				.chain(core::iter::once(last.clone()))
+				.collect::<Vec<_>>()
+				.try_into()
+				.expect("c is at most MAX_CONTACTS; qed")
+		};
+		Contacts::<T>::insert(&caller, filler(&contact));
+		Contacts::<T>::insert(&contact, filler(&caller));
+
+		#[extrinsic_call]
+		remove_contact(RawOrigin::Signed(caller.clone()), contact.clone());
+
+		assert!(!Contacts::<T>::get(&caller).contains(&contact));
+		assert!(!Contacts::<T>::get(&contact).contains(&caller));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -1,13 +1,35 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
 pub use pallet::*;
 
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+pub mod migrations;
+#[cfg(test)]
+mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+pub use weights::*;
+
 #[frame_support::pallet]
 pub mod pallet {
 	use frame_support::{pallet_prelude::*, BoundedVec};
 	use frame_system::pallet_prelude::*;
+	use sp_runtime::SaturatedConversion;
+
+	use crate::weights::WeightInfo;
+
+	/// Maximum number of contacts per user
+	pub const MAX_CONTACTS: u32 = 200;
+
+	/// Current storage version (1: registered users counted in `UserCount`)
+	pub const STORAGE_VERSION: StorageVersion = StorageVersion::new(1);
 
 	#[pallet::pallet]
+	#[pallet::storage_version(STORAGE_VERSION)]
 	pub struct Pallet<T>(_);
 
 	#[pallet::config]
@@ -28,7 +50,7 @@ pub mod pallet {
 		_,
 		Blake2_128Concat,
 		T::AccountId,
-		BoundedVec<T::AccountId, ConstU32<200>>,
+		BoundedVec<T::AccountId, ConstU32<MAX_CONTACTS>>,
 		ValueQuery,
 	>;
 
@@ -44,13 +66,21 @@ pub mod pallet {
 	pub type IvsCid<T: Config> =
 		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u8, ConstU32<128>>>;
 
+	// Storage: Number of registered users
+	#[pallet::storage]
+	#[pallet::getter(fn user_count)]
+	pub type UserCount<T: Config> = StorageValue<_, u32, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config> {
-		UserRegistered { who: T::AccountId },
-		ContactAdded { who: T::AccountId, contact: T::AccountId },
-		HealthCidSet { who: T::AccountId },
-		IvsCidSet { who: T::AccountId },
+		UserRegistered { who: T::AccountId, at: u64 },
+		ContactAdded { who: T::AccountId, contact: T::AccountId, at: u64 },
+		ContactRemoved { user: T::AccountId, contact: T::AccountId },
+		// Emitted once per undirected edge mutation, for live graph indexers
+		ContactGraphChanged { user: T::AccountId, contact: T::AccountId, added: bool },
+		HealthCidSet { who: T::AccountId, at: u64 },
+		IvsCidSet { who: T::AccountId, at: u64 },
 	}
 
 	#[pallet::error]
@@ -58,8 +88,10 @@ pub mod pallet {
 		AlreadyRegistered,
 		NotRegistered,
 		ContactAlreadyExists,
+		ContactNotFound,
 		TooManyContacts,
 		CidTooLarge,
+		SelfContactNotAllowed,
 	}
 
 	#[pallet::call]
@@ -72,15 +104,17 @@ pub mod pallet {
 			ensure!(!Users::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
 
 			Users::<T>::insert(&who, ());
-			Self::deposit_event(Event::UserRegistered { who });
+			UserCount::<T>::mutate(|count| *count = count.saturating_add(1));
+			Self::deposit_event(Event::UserRegistered { who, at: Self::current_block() });
 			Ok(())
 		}
 
 		/// Add a bidirectional contact relationship
 		#[pallet::call_index(1)]
-		#[pallet::weight(T::WeightInfo::add_contact())]
+		#[pallet::weight(T::WeightInfo::add_contact(MAX_CONTACTS))]
 		pub fn add_contact(origin: OriginFor<T>, contact: T::AccountId) -> DispatchResult {
 			let who = ensure_signed(origin)?;
+			ensure!(contact != who, Error::<T>::SelfContactNotAllowed);
 			ensure!(Users::<T>::contains_key(&who), Error::<T>::NotRegistered);
 			ensure!(Users::<T>::contains_key(&contact), Error::<T>::NotRegistered);
 
@@ -103,13 +137,18 @@ pub mod pallet {
 				Ok(())
 			})?;
 
-			Self::deposit_event(Event::ContactAdded { who, contact });
+			Self::deposit_event(Event::ContactGraphChanged {
+				user: who.clone(),
+				contact: contact.clone(),
+				added: true,
+			});
+			Self::deposit_event(Event::ContactAdded { who, contact, at: Self::current_block() });
 			Ok(())
 		}
 
 		/// Set IPFS CID for encrypted health status
 		#[pallet::call_index(2)]
-		#[pallet::weight(T::WeightInfo::set_health_cid())]
+		#[pallet::weight(T::WeightInfo::set_health_cid(cid.len() as u32))]
 		pub fn set_health_cid(origin: OriginFor<T>, cid: Vec<u8>) -> DispatchResult {
 			let who = ensure_signed(origin)?;
 			ensure!(Users::<T>::contains_key(&who), Error::<T>::NotRegistered);
@@ -118,7 +157,7 @@ pub mod pallet {
 				cid.try_into().map_err(|_| Error::<T>::CidTooLarge)?;
 
 			HealthCid::<T>::insert(&who, bounded_cid);
-			Self::deposit_event(Event::HealthCidSet { who });
+			Self::deposit_event(Event::HealthCidSet { who, at: Self::current_block() });
 			Ok(())
 		}
 
@@ -136,30 +175,49 @@ pub mod pallet {
 				cid.try_into().map_err(|_| Error::<T>::CidTooLarge)?;
 
 			IvsCid::<T>::insert(&user, bounded_cid);
-			Self::deposit_event(Event::IvsCidSet { who: user });
+			Self::deposit_event(Event::IvsCidSet { who: user, at: Self::current_block() });
 			Ok(())
 		}
-	}
 
-	pub trait WeightInfo {
-		fn register_user() -> Weight;
-		fn add_contact() -> Weight;
-		fn set_health_cid() -> Weight;
-		fn set_ivs_cid() -> Weight;
-	}
+		/// Remove a bidirectional contact relationship
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::remove_contact(MAX_CONTACTS))]
+		pub fn remove_contact(origin: OriginFor<T>, contact: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
 
-	impl WeightInfo for () {
-		fn register_user() -> Weight {
-			Weight::from_parts(10_000, 0)
-		}
-		fn add_contact() -> Weight {
-			Weight::from_parts(20_000, 0)
+			// Remove contact from who's list
+			Contacts::<T>::try_mutate(&who, |contacts| {
+				let index = contacts
+					.iter()
+					.position(|c| c == &contact)
+					.ok_or(Error::<T>::ContactNotFound)?;
+				contacts.remove(index);
+				Ok::<(), Error<T>>(())
+			})?;
+
+			// Remove who from contact's list (bidirectional)
+			Contacts::<T>::mutate(&contact, |contacts| contacts.retain(|c| c != &who));
+
+			Self::deposit_event(Event::ContactGraphChanged {
+				user: who.clone(),
+				contact: contact.clone(),
+				added: false,
+			});
+			Self::deposit_event(Event::ContactRemoved { user: who, contact });
+			Ok(())
 		}
-		fn set_health_cid() -> Weight {
-			Weight::from_parts(15_000, 0)
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Current block number, carried by events for indexers
+		fn current_block() -> u64 {
+			frame_system::Pallet::<T>::block_number().saturated_into::<u64>()
 		}
-		fn set_ivs_cid() -> Weight {
-			Weight::from_parts(15_000, 0)
+	}
+
+	impl<T: Config> ivs_primitives::ParticipationMetrics for Pallet<T> {
+		fn user_count() -> u32 {
+			UserCount::<T>::get()
 		}
 	}
 }
@@ -0,0 +1,32 @@
+//! Storage migrations for the ivs pallet.
+
+use super::*;
+use frame_support::{
+	migrations::VersionedMigration, pallet_prelude::*, traits::UncheckedOnRuntimeUpgrade,
+};
+
+/// Migration counting the users registered before `UserCount` was maintained.
+pub mod v1 {
+	use super::*;
+
+	/// Sets `UserCount` to the number of entries in `Users`.
+	pub struct InnerMigrateV0ToV1<T>(core::marker::PhantomData<T>);
+
+	impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV0ToV1<T> {
+		fn on_runtime_upgrade() -> Weight {
+			let users = Users::<T>::iter_keys().count() as u64;
+			UserCount::<T>::put(users as u32);
+
+			T::DbWeight::get().reads_writes(users, 1)
+		}
+	}
+
+	/// [`InnerMigrateV0ToV1`] gated on the on-chain storage version being 0.
+	pub type MigrateV0ToV1<T> = VersionedMigration<
+		0,
+		1,
+		InnerMigrateV0ToV1<T>,
+		Pallet<T>,
+		<T as frame_system::Config>::DbWeight,
+	>;
+}
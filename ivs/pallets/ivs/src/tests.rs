@@ -0,0 +1,54 @@
+//! Unit tests for pallet-ivs
+
+use crate::{mock::*, Error, Event, Users};
+use frame_support::{assert_noop, assert_ok};
+use ivs_primitives::ParticipationMetrics;
+
+fn register(who: u64) {
+	assert_ok!(Ivs::register_user(RuntimeOrigin::signed(who)));
+}
+
+#[test]
+fn contact_graph_changed_reports_add_and_remove() {
+	new_test_ext().execute_with(|| {
+		register(1);
+		register(2);
+
+		assert_ok!(Ivs::add_contact(RuntimeOrigin::signed(1), 2));
+		System::assert_has_event(
+			Event::ContactGraphChanged { user: 1, contact: 2, added: true }.into(),
+		);
+
+		assert_ok!(Ivs::remove_contact(RuntimeOrigin::signed(1), 2));
+		System::assert_has_event(
+			Event::ContactGraphChanged { user: 1, contact: 2, added: false }.into(),
+		);
+		assert!(Ivs::contacts(1).is_empty());
+		assert!(Ivs::contacts(2).is_empty());
+	});
+}
+
+#[test]
+fn participation_metrics_match_storage() {
+	new_test_ext().execute_with(|| {
+		register(1);
+		register(2);
+
+		assert_eq!(<Ivs as ParticipationMetrics>::user_count(), 2);
+		assert_eq!(<Ivs as ParticipationMetrics>::user_count(), Ivs::user_count());
+		assert_eq!(Users::<Test>::iter().count(), 2);
+	});
+}
+
+#[test]
+fn self_contact_is_rejected() {
+	new_test_ext().execute_with(|| {
+		register(1);
+
+		assert_noop!(
+			Ivs::add_contact(RuntimeOrigin::signed(1), 1),
+			Error::<Test>::SelfContactNotAllowed
+		);
+		assert!(Ivs::contacts(1).is_empty());
+	});
+}
@@ -0,0 +1,104 @@
+//! Weights for `pallet_ivs`
+//!
+//! Hand-written conservative estimates, not benchmark output: no runtime
+//! includes this pallet yet, so it has not been benchmarked.
+//!
+//! Each weight is a rounded-up execution allowance plus the storage reads and
+//! writes of the call's worst-case path, as listed on each `WeightInfo` method.
+//! Replace this file with `frame-omni-bencher` output once the pallet can be
+//! benchmarked in a runtime.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+#![allow(missing_docs)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use core::marker::PhantomData;
+
+/// Weight functions needed for `pallet_ivs`.
+pub trait WeightInfo {
+	/// - `Users`: 1 read, 1 write
+	/// - `UserCount`: 1 read, 1 write
+	fn register_user() -> Weight;
+	/// `c`: contacts already on the longer of the two lists
+	/// - `Users`: 2 reads
+	/// - `Contacts`: 2 reads, 2 writes
+	fn add_contact(c: u32, ) -> Weight;
+	/// `l`: CID length in bytes
+	/// - `Users`: 1 read
+	/// - `HealthCid`: 1 write
+	fn set_health_cid(l: u32, ) -> Weight;
+	/// - `IvsCid`: 1 write
+	fn set_ivs_cid() -> Weight;
+	/// `c`: contacts on the longer of the two lists
+	/// - `Contacts`: 2 reads, 2 writes
+	fn remove_contact(c: u32, ) -> Weight;
+}
+
+/// Weights for `pallet_ivs` priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	fn register_user() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	fn add_contact(c: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 6036)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(c.into()))
+	}
+	fn set_health_cid(l: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3513)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(l.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn set_ivs_cid() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	fn remove_contact(c: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 5972)
+			.saturating_add(Weight::from_parts(170_000, 0).saturating_mul(c.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(c.into()))
+	}
+}
+
+// For backwards compatibility and tests.
+impl WeightInfo for () {
+	fn register_user() -> Weight {
+		Weight::from_parts(12_000_000, 3513)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	fn add_contact(c: u32, ) -> Weight {
+		Weight::from_parts(25_000_000, 6036)
+			.saturating_add(Weight::from_parts(150_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(c.into()))
+	}
+	fn set_health_cid(l: u32, ) -> Weight {
+		Weight::from_parts(15_000_000, 3513)
+			.saturating_add(Weight::from_parts(1_400, 0).saturating_mul(l.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn set_ivs_cid() -> Weight {
+		Weight::from_parts(10_000_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	fn remove_contact(c: u32, ) -> Weight {
+		Weight::from_parts(20_000_000, 5972)
+			.saturating_add(Weight::from_parts(170_000, 0).saturating_mul(c.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(Weight::from_parts(0, 64).saturating_mul(c.into()))
+	}
+}
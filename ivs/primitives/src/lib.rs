@@ -0,0 +1,121 @@
+//! # IVS Primitives
+//!
+//! Types and traits shared by the ivs, disease-tracker and aggregator pallets.
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Get, RuntimeDebug};
+
+/// Length of a base58btc-encoded CIDv0 (`Qm...`)
+pub const CID_V0_LEN: usize = 46;
+
+/// Default CID prefixes: base58btc CIDv0 (`Qm`) and base32 CIDv1 (`bafy`/`bafk`)
+pub struct DefaultCidPrefixes;
+impl Get<&'static [&'static [u8]]> for DefaultCidPrefixes {
+    fn get() -> &'static [&'static [u8]] {
+        const PREFIXES: &[&[u8]] = &[b"Qm", b"bafy", b"bafk"];
+        PREFIXES
+    }
+}
+
+/// Whether `bytes` is structurally a CID: a base58btc CIDv0 (`Qm`, 46 chars)
+/// or a CIDv1 whose body uses the alphabet of its multibase prefix.
+///
+/// Which prefixes a pallet accepts is up to its allow-list; this only rejects
+/// bytes that cannot be a CID in the encoding they claim.
+pub fn is_well_formed_cid(bytes: &[u8]) -> bool {
+    match bytes {
+        [b'Q', b'm', ..] => bytes.len() == CID_V0_LEN && bytes.iter().all(is_base58btc),
+        [multibase, rest @ ..] => {
+            !rest.is_empty() && rest.iter().all(|c| in_multibase_alphabet(*multibase, c))
+        }
+        _ => false,
+    }
+}
+
+/// Whether `c` belongs to the alphabet of the multibase encoding `multibase`.
+///
+/// Covers the encodings CIDv1s are commonly written in; any other prefix is
+/// rejected.
+fn in_multibase_alphabet(multibase: u8, c: &u8) -> bool {
+    match multibase {
+        b'b' => matches!(c, b'a'..=b'z' | b'2'..=b'7'),
+        b'B' => matches!(c, b'A'..=b'Z' | b'2'..=b'7'),
+        b'z' => is_base58btc(c),
+        b'f' => matches!(c, b'0'..=b'9' | b'a'..=b'f'),
+        b'F' => matches!(c, b'0'..=b'9' | b'A'..=b'F'),
+        b'k' => c.is_ascii_digit() || c.is_ascii_lowercase(),
+        b'K' => c.is_ascii_digit() || c.is_ascii_uppercase(),
+        b'm' => c.is_ascii_alphanumeric() || matches!(c, b'+' | b'/'),
+        b'u' => c.is_ascii_alphanumeric() || matches!(c, b'-' | b'_'),
+        _ => false,
+    }
+}
+
+fn is_base58btc(c: &u8) -> bool {
+    c.is_ascii_alphanumeric() && !matches!(c, b'0' | b'O' | b'I' | b'l')
+}
+
+/// Participation counters a pallet can report.
+///
+/// Each pallet implements the metrics it tracks; the rest default to zero.
+pub trait ParticipationMetrics {
+    /// Number of registered users
+    fn user_count() -> u32 {
+        0
+    }
+
+    /// Number of committee members
+    fn committee_size() -> u32 {
+        0
+    }
+
+    /// Number of recompute requests waiting in `Pending` status
+    fn pending_request_count() -> u32 {
+        0
+    }
+}
+
+/// Compose several pallets' metrics by summing each counter
+impl<A: ParticipationMetrics, B: ParticipationMetrics, C: ParticipationMetrics>
+    ParticipationMetrics for (A, B, C)
+{
+    fn user_count() -> u32 {
+        A::user_count()
+            .saturating_add(B::user_count())
+            .saturating_add(C::user_count())
+    }
+
+    fn committee_size() -> u32 {
+        A::committee_size()
+            .saturating_add(B::committee_size())
+            .saturating_add(C::committee_size())
+    }
+
+    fn pending_request_count() -> u32 {
+        A::pending_request_count()
+            .saturating_add(B::pending_request_count())
+            .saturating_add(C::pending_request_count())
+    }
+}
+
+/// Point-in-time participation metrics
+#[derive(Clone, Default, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct MetricsSnapshot {
+    pub user_count: u32,
+    pub committee_size: u32,
+    pub pending_request_count: u32,
+}
+
+impl MetricsSnapshot {
+    /// Read every metric from `M`
+    pub fn of<M: ParticipationMetrics>() -> Self {
+        Self {
+            user_count: M::user_count(),
+            committee_size: M::committee_size(),
+            pending_request_count: M::pending_request_count(),
+        }
+    }
+}